@@ -156,6 +156,7 @@
 #![feature(try_trait_v2)]
 #![feature(try_with_capacity)]
 #![feature(tuple_trait)]
+#![feature(ub_checks)]
 #![feature(unicode_internals)]
 #![feature(unsize)]
 #![feature(unwrap_infallible)]
@@ -164,6 +165,7 @@
 //
 // Language features:
 // tidy-alphabetical-start
+#![cfg_attr(kani, feature(kani))]
 #![cfg_attr(not(test), feature(coroutine_trait))]
 #![cfg_attr(test, feature(panic_update_hook))]
 #![cfg_attr(test, feature(test))]
@@ -184,6 +186,7 @@
 #![feature(negative_impls)]
 #![feature(never_type)]
 #![feature(optimize_attribute)]
+#![feature(proc_macro_hygiene)]
 #![feature(rustc_allow_const_fn_unstable)]
 #![feature(rustc_attrs)]
 #![feature(slice_internals)]
@@ -213,6 +216,10 @@ extern crate test;
 #[cfg(test)]
 mod testing;
 
+// Verification support from `core`, available under the same paths as in `core` so that
+// contracts and `mod verify` blocks can be written the same way in this crate.
+safety::verify_lib!(alloc);
+
 // Module with internal macros used by other modules (needs to be included before other modules).
 #[macro_use]
 mod macros;
@@ -3095,3 +3095,24 @@ impl From<char> for String {
         c.to_string()
     }
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+    use crate::ub_checks;
+
+    // pub fn remove(&mut self, idx: usize) -> char
+    #[safety::harness]
+    #[safety::stub_verified(<*const u8>::add)]
+    #[safety::stub_verified(<*mut u8>::add)]
+    fn check_remove() {
+        let chars: [char; 3] = ub_checks::any();
+        let index = ub_checks::any_in(0..chars.len());
+        let mut string: String = chars.iter().collect();
+        let len = string.len();
+        let idx = chars[..index].iter().map(|c| c.len_utf8()).sum();
+        assert_eq!(string.remove(idx), chars[index]);
+        assert_eq!(string.len(), len - chars[index].len_utf8());
+    }
+}
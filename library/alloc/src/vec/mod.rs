@@ -1483,6 +1483,8 @@ impl<T, A: Allocator> Vec<T, A> {
     /// the contents and thus not leak memory.
     #[inline]
     #[stable(feature = "rust1", since = "1.0.0")]
+    #[safety::requires(new_len <= self.capacity())]
+    #[safety::ensures(|_| self.len() == new_len)]
     pub unsafe fn set_len(&mut self, new_len: usize) {
         debug_assert!(new_len <= self.capacity());
 
@@ -3593,3 +3595,35 @@ impl<T, A: Allocator, const N: usize> TryFrom<Vec<T, A>> for [T; N] {
         Ok(array)
     }
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+    use crate::ub_checks;
+
+    // pub unsafe fn set_len(&mut self, new_len: usize)
+    #[safety::proof_for_contract(Vec::set_len)]
+    fn check_set_len() {
+        let array: [u32; 4] = ub_checks::any();
+        let mut vec = Vec::from(array);
+        let new_len = ub_checks::any_in(0..=vec.capacity());
+        unsafe { vec.set_len(new_len) };
+        assert_eq!(vec[..], array[..new_len]);
+    }
+
+    // pub fn swap_remove(&mut self, index: usize) -> T
+    #[safety::harness]
+    #[safety::stub_verified(<*const u32>::add)]
+    #[safety::stub_verified(<*mut u32>::add)]
+    fn check_swap_remove() {
+        let array: [u32; 4] = ub_checks::any();
+        let index = ub_checks::any_in(0..array.len());
+        let mut vec = Vec::from(array);
+        assert_eq!(vec.swap_remove(index), array[index]);
+        assert_eq!(vec.len(), array.len() - 1);
+        if index < vec.len() {
+            assert_eq!(vec[index], array[array.len() - 1]);
+        }
+    }
+}
@@ -1,4 +1,9 @@
 fn main() {
     // We add the configurations here to be checked.
+    //
+    // `kani verify-std` sets `kani_host` on host crates, so it is seen by this proc-macro crate
+    // rather than by the crates that use it. The one build of this crate expands the contracts of
+    // `core`, `alloc` and `std` alike, so those crates only declare the `kani` cfg that Kani
+    // sets on them.
     println!("cargo:rustc-check-cfg=cfg(kani_host)");
 }
@@ -1,6 +1,7 @@
 use proc_macro::{TokenStream};
 use quote::{quote, format_ident};
-use syn::{ItemFn, parse_macro_input};
+use syn::{DeriveInput, ItemFn, Stmt, parse_macro_input};
+use crate::quantifier::Quantifier;
 
 pub(crate) fn requires(attr: TokenStream, item: TokenStream) -> TokenStream {
     rewrite_attr(attr, item, "requires")
@@ -10,12 +11,107 @@ pub(crate) fn ensures(attr: TokenStream, item: TokenStream) -> TokenStream {
     rewrite_attr(attr, item, "ensures")
 }
 
+/// Kani handles `old` natively in `ensures`, and ghost statements are kept as regular statements.
+pub(crate) fn ghost(stmt: Stmt) -> TokenStream {
+    quote!(
+        #[allow(unused_variables)]
+        #stmt
+    ).into()
+}
+
+/// Kani derives the implementation of its `Invariant` trait from a type-level safety constraint.
+/// Same as `derive(kani::Arbitrary)`, this expects `kani` to be in scope where the type is
+/// declared.
+pub(crate) fn invariant(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let args = proc_macro2::TokenStream::from(attr);
+    let type_item = parse_macro_input!(item as DeriveInput);
+    quote!(
+        #[derive(kani::Invariant)]
+        #[safety_constraint(#args)]
+        #type_item
+    ).into()
+}
+
+pub(crate) fn modifies(attr: TokenStream, item: TokenStream) -> TokenStream {
+    rewrite_attr(attr, item, "modifies")
+}
+
+pub(crate) fn harness(attr: TokenStream, item: TokenStream) -> TokenStream {
+    rewrite_attr(attr, item, "proof")
+}
+
+pub(crate) fn proof_for_contract(attr: TokenStream, item: TokenStream) -> TokenStream {
+    rewrite_attr(attr, item, "proof_for_contract")
+}
+
+pub(crate) fn should_panic(attr: TokenStream, item: TokenStream) -> TokenStream {
+    rewrite_attr(attr, item, "should_panic")
+}
+
+pub(crate) fn stub_verified(attr: TokenStream, item: TokenStream) -> TokenStream {
+    rewrite_attr(attr, item, "stub_verified")
+}
+
+pub(crate) fn loop_invariant(attr: TokenStream, item: TokenStream) -> TokenStream {
+    rewrite_loop_attr(attr, item, "loop_invariant")
+}
+
+pub(crate) fn loop_modifies(attr: TokenStream, item: TokenStream) -> TokenStream {
+    rewrite_loop_attr(attr, item, "loop_modifies")
+}
+
+pub(crate) fn decreases(attr: TokenStream, item: TokenStream) -> TokenStream {
+    rewrite_loop_attr(attr, item, "loop_decreases")
+}
+
+/// Set up a crate other than `core` for verification, see `verify_lib!`.
+///
+/// Kani itself is set up in `core` with `kani_core::kani_lib!(core)`, so the other crates of the
+/// library only need to import it.
+pub(crate) fn verify_lib() -> TokenStream {
+    quote!(
+        #[allow(unused_imports)]
+        use ::core::kani;
+        #[allow(unused_imports)]
+        use ::core::ub_checks;
+    ).into()
+}
+
+/// Every crate of the library reaches the Kani attributes through `core`, where they are
+/// re-exported by `kani_core::kani_lib!(core)`.
 fn rewrite_attr(attr: TokenStream, item: TokenStream, name: &str) -> TokenStream {
     let args = proc_macro2::TokenStream::from(attr);
     let fn_item = parse_macro_input!(item as ItemFn);
     let attribute = format_ident!("{}", name);
     quote!(
-        #[kani_core::#attribute(#args)]
+        #[::core::kani::#attribute(#args)]
         #fn_item
     ).into()
 }
+
+/// Loop contracts are attached to the loop expression itself, so we just forward the tokens.
+fn rewrite_loop_attr(attr: TokenStream, item: TokenStream, name: &str) -> TokenStream {
+    let args = proc_macro2::TokenStream::from(attr);
+    let loop_item = proc_macro2::TokenStream::from(item);
+    let attribute = format_ident!("{}", name);
+    quote!(
+        #[::core::kani::#attribute(#args)]
+        #loop_item
+    ).into()
+}
+
+pub(crate) fn forall(quantifier: Quantifier) -> TokenStream {
+    rewrite_quantifier(quantifier, "forall")
+}
+
+pub(crate) fn exists(quantifier: Quantifier) -> TokenStream {
+    rewrite_quantifier(quantifier, "exists")
+}
+
+fn rewrite_quantifier(quantifier: Quantifier, name: &str) -> TokenStream {
+    let Quantifier { var, lower, upper, predicate } = quantifier;
+    let quantifier = format_ident!("{}", name);
+    quote!(
+        ::core::kani::#quantifier!(|#var in (#lower, #upper)| #predicate)
+    ).into()
+}
@@ -2,7 +2,9 @@
 //! Each tool should implement their own version in a separate module of this crate.
 
 use proc_macro::TokenStream;
-use proc_macro_error::proc_macro_error;
+use proc_macro_error::{abort, proc_macro_error};
+use quote::quote;
+use syn::parse_macro_input;
 
 #[cfg(kani_host)]
 #[path = "kani.rs"]
@@ -12,14 +14,168 @@ mod tool;
 #[path = "runtime.rs"]
 mod tool;
 
+mod quantifier;
+
 #[proc_macro_error]
 #[proc_macro_attribute]
 pub fn requires(attr: TokenStream, item: TokenStream) -> TokenStream {
     tool::requires(attr, item)
 }
 
+/// Declare a postcondition as a closure over a reference to the return value.
+///
+/// The closure may refer to the value an expression had before the call with `old(expr)`.
 #[proc_macro_error]
 #[proc_macro_attribute]
 pub fn ensures(attr: TokenStream, item: TokenStream) -> TokenStream {
     tool::ensures(attr, item)
 }
+
+/// Declare a ghost statement, i.e., a statement that is only visible to verification tools.
+///
+/// This must annotate either a `let` statement, which declares a ghost binding, or an expression
+/// statement, which usually updates ghost state such as a `core::ub_checks::GhostFlag`. Ghost
+/// statements are erased when compiled without a verification tool, so ghost bindings can only be
+/// used inside other contract annotations.
+#[proc_macro_error]
+#[proc_macro_attribute]
+pub fn ghost(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    // The semicolon of an expression statement is not part of the annotated tokens, but the
+    // statement must not become the value of its block.
+    let stmt = syn::parse::<syn::Stmt>(item.clone())
+        .or_else(|_| syn::parse::<syn::Expr>(item).map(|expr| syn::Stmt::Expr(expr, None)));
+    match stmt {
+        Ok(stmt @ syn::Stmt::Local(_)) => tool::ghost(stmt),
+        Ok(syn::Stmt::Expr(expr, _)) => {
+            tool::ghost(syn::Stmt::Expr(expr, Some(Default::default())))
+        }
+        Ok(stmt) => abort!(stmt, "`ghost` can only be applied to `let` and expression statements"),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Declare the memory locations a function may modify.
+#[proc_macro_error]
+#[proc_macro_attribute]
+pub fn modifies(attr: TokenStream, item: TokenStream) -> TokenStream {
+    tool::modifies(attr, item)
+}
+
+/// Declare an invariant that holds at the start of every iteration of the annotated loop.
+#[proc_macro_error]
+#[proc_macro_attribute]
+pub fn loop_invariant(attr: TokenStream, item: TokenStream) -> TokenStream {
+    tool::loop_invariant(attr, item)
+}
+
+/// Declare the memory locations the annotated loop may modify.
+#[proc_macro_error]
+#[proc_macro_attribute]
+pub fn loop_modifies(attr: TokenStream, item: TokenStream) -> TokenStream {
+    tool::loop_modifies(attr, item)
+}
+
+/// Declare a measure that strictly decreases on every iteration of the annotated loop.
+#[proc_macro_error]
+#[proc_macro_attribute]
+pub fn decreases(attr: TokenStream, item: TokenStream) -> TokenStream {
+    tool::decreases(attr, item)
+}
+
+/// Mark a function as a verification harness.
+///
+/// Harnesses should use `any!` to generate their inputs. Without a verification tool, the harness
+/// becomes a unit test that runs a fixed number of times with pseudo-random inputs. `core` has no
+/// unit tests of its own, so only its harnesses that `coretests` includes run as unit tests.
+#[proc_macro_error]
+#[proc_macro_attribute]
+pub fn harness(attr: TokenStream, item: TokenStream) -> TokenStream {
+    tool::harness(attr, item)
+}
+
+/// Mark a function as a harness that verifies the contract of the given function.
+///
+/// Same as `harness`, this becomes a unit test without a verification tool. Since preconditions
+/// are then checked instead of assumed, the harness must only call the function with inputs that
+/// satisfy its preconditions.
+#[proc_macro_error]
+#[proc_macro_attribute]
+pub fn proof_for_contract(attr: TokenStream, item: TokenStream) -> TokenStream {
+    tool::proof_for_contract(attr, item)
+}
+
+/// Mark a harness as expected to fail, e.g., because it violates a precondition on purpose.
+///
+/// Without a verification tool, the unit test of the harness is ignored, since the failure may
+/// not be detected, or may abort the test process, as a precondition that does not hold does.
+#[proc_macro_error]
+#[proc_macro_attribute]
+pub fn should_panic(attr: TokenStream, item: TokenStream) -> TokenStream {
+    tool::should_panic(attr, item)
+}
+
+/// Set up verification in a crate of the library other than `core`: `verify_lib!(alloc)`.
+///
+/// This brings `ub_checks` and, under Kani, `kani` into scope at the root of the crate, so that
+/// contracts and `mod verify` blocks can be written the same way as in `core`, which is set up with
+/// `kani_core::kani_lib!(core)` instead.
+#[proc_macro_error]
+#[proc_macro]
+pub fn verify_lib(item: TokenStream) -> TokenStream {
+    let krate = parse_macro_input!(item as syn::Ident);
+    if krate != "alloc" && krate != "std" {
+        abort!(krate, "`verify_lib!` expects `alloc` or `std`");
+    }
+    tool::verify_lib()
+}
+
+/// Generate an arbitrary value: `any!()`, or `any!(T)` to name its type.
+///
+/// This is `core::ub_checks::any`, which is `kani::any` under Kani. It is a macro because a
+/// procedural macro crate cannot export functions.
+#[proc_macro]
+pub fn any(item: TokenStream) -> TokenStream {
+    if item.is_empty() {
+        return quote!(::core::ub_checks::any()).into();
+    }
+    let ty = parse_macro_input!(item as syn::Type);
+    quote!(::core::ub_checks::any::<#ty>()).into()
+}
+
+/// Replace calls to the given function by its contract in the annotated harness.
+///
+/// The contract of the stubbed function must itself be verified by a `proof_for_contract`
+/// harness.
+#[proc_macro_error]
+#[proc_macro_attribute]
+pub fn stub_verified(attr: TokenStream, item: TokenStream) -> TokenStream {
+    tool::stub_verified(attr, item)
+}
+
+/// Declare a type invariant, i.e., a property that every safe value of the annotated type holds.
+///
+/// This generates an implementation of `core::ub_checks::Invariant`, where the expression
+/// is evaluated with `self: &Self` in scope.
+#[proc_macro_error]
+#[proc_macro_attribute]
+pub fn invariant(attr: TokenStream, item: TokenStream) -> TokenStream {
+    tool::invariant(attr, item)
+}
+
+/// Universal quantifier over a bounded range: `forall!(|i in lower..upper| predicate)`.
+///
+/// Evaluates to `true` if `predicate` holds for every `i` in `lower..upper`.
+#[proc_macro_error]
+#[proc_macro]
+pub fn forall(item: TokenStream) -> TokenStream {
+    tool::forall(parse_macro_input!(item as quantifier::Quantifier))
+}
+
+/// Existential quantifier over a bounded range: `exists!(|i in lower..upper| predicate)`.
+///
+/// Evaluates to `true` if `predicate` holds for some `i` in `lower..upper`.
+#[proc_macro_error]
+#[proc_macro]
+pub fn exists(item: TokenStream) -> TokenStream {
+    tool::exists(parse_macro_input!(item as quantifier::Quantifier))
+}
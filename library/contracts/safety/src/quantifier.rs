@@ -0,0 +1,51 @@
+use proc_macro2::{TokenStream, TokenTree};
+use syn::parse::{Parse, ParseStream};
+use syn::{Expr, Ident, RangeLimits, Token};
+
+/// A bounded quantifier of the form `|i in lower..upper| predicate`.
+///
+/// The range is half-open, i.e., `upper` is excluded.
+pub(crate) struct Quantifier {
+    pub(crate) var: Ident,
+    pub(crate) lower: Expr,
+    pub(crate) upper: Expr,
+    pub(crate) predicate: Expr,
+}
+
+impl Parse for Quantifier {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        input.parse::<Token![|]>()?;
+        let var: Ident = input.parse()?;
+        input.parse::<Token![in]>()?;
+
+        // The range ends at the first top-level `|`, which we cannot leave to the expression
+        // parser since it would be interpreted as a bitwise or.
+        let mut range = TokenStream::new();
+        loop {
+            if input.is_empty() {
+                return Err(input.error("expected `|` after the quantifier range"));
+            }
+            if input.peek(Token![|]) && !input.peek(Token![||]) {
+                input.parse::<Token![|]>()?;
+                break;
+            }
+            range.extend([input.parse::<TokenTree>()?]);
+        }
+        let (lower, upper) = match syn::parse2::<Expr>(range.clone())? {
+            Expr::Range(syn::ExprRange {
+                start: Some(start),
+                limits: RangeLimits::HalfOpen(_),
+                end: Some(end),
+                ..
+            }) => (*start, *end),
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    range,
+                    "quantifier range must be of the form `lower..upper`",
+                ));
+            }
+        };
+        let predicate: Expr = input.parse()?;
+        Ok(Quantifier { var, lower, upper, predicate })
+    }
+}
@@ -1,15 +1,378 @@
 use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{quote, ToTokens};
+use proc_macro_error::abort;
+use syn::visit_mut::VisitMut;
+use syn::{
+    parse_macro_input, DeriveInput, Expr, ExprBlock, Item, ItemFn, ReturnType, Stmt, Type,
+};
 
-/// For now, runtime requires is a no-op.
+use crate::quantifier::Quantifier;
+
+/// At runtime, `requires` becomes an unsafe precondition check, similar to the ones generated by
+/// `assert_unsafe_precondition!`.
+///
+/// The check is only executed if `ub_checks` are enabled. In a `const fn`, it is only executed at
+/// runtime, since contract expressions are not guaranteed to be const-evaluable.
+pub(crate) fn requires(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let cond = parse_macro_input!(attr as Expr);
+    let mut fn_item = parse_macro_input!(item as ItemFn);
+    let is_const = allow_runtime_checks(&mut fn_item);
+
+    let msg = format!("unsafe precondition(s) violated: {}", quote!(#cond));
+    let check = check_expr(quote!(#cond), &msg, is_const);
+    // The label lets `ensures` keep the preconditions ahead of its own snapshots.
+    let label = syn::Lifetime::new(REQUIRES_LABEL, proc_macro2::Span::call_site());
+    fn_item.block.stmts.insert(0, syn::parse_quote!(#label: { #check }));
+    quote!(#fn_item).into()
+}
+
+/// At runtime, `ensures` evaluates the given closure on a reference to the return value, and
+/// panics if the postcondition does not hold.
+///
+/// Same as `requires`, this is only executed if `ub_checks` are enabled, and only at runtime in a
+/// `const fn`. Every `old(expr)` in the closure is replaced by a clone of `expr` taken before the
+/// function body runs, which is only evaluated if the check is enabled.
+pub(crate) fn ensures(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let mut closure = parse_macro_input!(attr as Expr);
+    let mut fn_item = parse_macro_input!(item as ItemFn);
+    let is_const = allow_runtime_checks(&mut fn_item);
+
+    let mut old_visitor = OldVisitor { snapshots: Vec::new() };
+    old_visitor.visit_expr_mut(&mut closure);
+    let snapshots = old_visitor.snapshots.iter().enumerate().map(|(idx, expr)| {
+        let name = old_ident(idx);
+        let snapshot = quote!(
+            if ::core::ub_checks::check_library_ub() {
+                Some(::core::clone::Clone::clone(&(#expr)))
+            } else {
+                None
+            }
+        );
+        let snapshot = if is_const { runtime_only(snapshot) } else { snapshot };
+        quote!(let #name = #snapshot;)
+    });
+
+    // Preconditions must be checked before taking any snapshot.
+    let stmts = &mut fn_item.block.stmts;
+    let num_requires = stmts.iter().take_while(|stmt| is_requires_check(stmt)).count();
+    let requires_checks: Vec<Stmt> = stmts.drain(..num_requires).collect();
+
+    // Closures cannot be annotated with `impl Trait`, so let inference handle that case. Mutable
+    // references are left to inference too: with an annotation, returning a captured `&mut`
+    // reborrows it, which cannot escape the closure, whereas otherwise it is moved out.
+    let ret_ty = match &fn_item.sig.output {
+        ReturnType::Default => quote!(-> ()),
+        ReturnType::Type(_, ty) => match &**ty {
+            Type::ImplTrait(_) => quote!(),
+            Type::Reference(reference) if reference.mutability.is_some() => quote!(),
+            ty => quote!(-> #ty),
+        },
+    };
+    let msg = format!("safety postcondition(s) violated: {}", quote!(#closure));
+    let check = check_expr(quote!(__safety_check_post(&__safety_result, #closure)), &msg, is_const);
+    // Closures cannot be called in a `const fn`, so its body is evaluated as a block instead, after
+    // turning every `return` into a `break` out of that block.
+    let body = if is_const {
+        let mut return_visitor = ReturnVisitor { found: false };
+        return_visitor.visit_block_mut(&mut fn_item.block);
+        let block = &fn_item.block;
+        if return_visitor.found {
+            let label = syn::Lifetime::new(BODY_LABEL, proc_macro2::Span::call_site());
+            quote!(#label: #block)
+        } else {
+            quote!(#block)
+        }
+    } else {
+        let block = &fn_item.block;
+        quote!({
+            #[allow(unused_mut)]
+            let mut __safety_body = || #ret_ty #block;
+            __safety_body()
+        })
+    };
+    fn_item.block = syn::parse_quote!({
+        // Helper used to give the contract closure its argument type.
+        #[inline(always)]
+        fn __safety_check_post<R, F: FnOnce(&R) -> bool>(result: &R, f: F) -> bool {
+            f(result)
+        }
+        #(#requires_checks)*
+        #(#snapshots)*
+        let __safety_result = #body;
+        #check
+        __safety_result
+    });
+    quote!(#fn_item).into()
+}
+
+/// Returns whether `fn_item` is a `const fn`.
+///
+/// Runtime checks in a `const fn` rely on `const_eval_select`, which a const-stable function must
+/// explicitly be allowed to use.
+fn allow_runtime_checks(fn_item: &mut ItemFn) -> bool {
+    if fn_item.sig.constness.is_none() {
+        return false;
+    }
+    let has_attr = |name: &str, arg: &str| {
+        fn_item.attrs.iter().any(|attr| {
+            attr.path().is_ident(name) && attr.to_token_stream().to_string().contains(arg)
+        })
+    };
+    let is_const_stable = has_attr("rustc_const_stable", "");
+    if is_const_stable && !has_attr("rustc_allow_const_fn_unstable", "const_eval_select") {
+        fn_item.attrs.push(syn::parse_quote!(
+            #[rustc_allow_const_fn_unstable(const_eval_select)]
+        ));
+    }
+    true
+}
+
+/// Label used to mark the statements generated by `requires`.
+const REQUIRES_LABEL: &str = "'__safety_requires";
+
+fn is_requires_check(stmt: &Stmt) -> bool {
+    match stmt {
+        Stmt::Expr(Expr::Block(ExprBlock { label: Some(label), .. }), _) => {
+            label.name.to_string() == REQUIRES_LABEL
+        }
+        _ => false,
+    }
+}
+
+/// Label of the block that holds the body of a `const fn` with postconditions.
+const BODY_LABEL: &str = "'__safety_body";
+
+fn old_ident(idx: usize) -> syn::Ident {
+    quote::format_ident!("__safety_old_{}", idx)
+}
+
+/// Replace every `old(expr)` by the variable that holds its snapshot, and collect the `expr`s.
+struct OldVisitor {
+    snapshots: Vec<Expr>,
+}
+
+impl VisitMut for OldVisitor {
+    fn visit_expr_mut(&mut self, expr: &mut Expr) {
+        if let Expr::Call(call) = expr {
+            if matches!(&*call.func, Expr::Path(path) if path.path.is_ident("old")) {
+                if call.args.len() != 1 {
+                    abort!(call, "`old` expects exactly one argument");
+                }
+                let name = old_ident(self.snapshots.len());
+                self.snapshots.push(call.args[0].clone());
+                *expr = syn::parse_quote!(#name.unwrap());
+                return;
+            }
+        }
+        syn::visit_mut::visit_expr_mut(self, expr);
+    }
+}
+
+/// Replace every `return` of a function body by a `break` out of the block labeled `BODY_LABEL`.
+struct ReturnVisitor {
+    found: bool,
+}
+
+impl VisitMut for ReturnVisitor {
+    fn visit_expr_mut(&mut self, expr: &mut Expr) {
+        match expr {
+            // A `return` in a closure or an `async` block does not return from the function.
+            Expr::Closure(_) | Expr::Async(_) => {}
+            Expr::Return(ret) => {
+                if let Some(value) = &mut ret.expr {
+                    self.visit_expr_mut(value);
+                }
+                let label = syn::Lifetime::new(BODY_LABEL, ret.return_token.span);
+                let value = &ret.expr;
+                *expr = syn::parse_quote!(break #label #value);
+                self.found = true;
+            }
+            _ => syn::visit_mut::visit_expr_mut(self, expr),
+        }
+    }
+
+    fn visit_item_mut(&mut self, _item: &mut Item) {
+        // Nested items have their own bodies.
+    }
+}
+
+/// Generate the runtime check for `cond`.
+///
+/// The check is gated on `ub_checks` the same way `assert_unsafe_precondition!` gates library
+/// UB checks, and it panics without unwinding. In a `const fn`, `cond` is only evaluated at
+/// runtime.
+fn check_expr(cond: TokenStream2, msg: &str, is_const: bool) -> TokenStream2 {
+    if is_const {
+        // The values captured by `cond` are moved into the closure, so it must be created whether
+        // or not the check is enabled.
+        let cond = runtime_only(quote!(
+            if ::core::ub_checks::check_library_ub() { Some(#cond) } else { None }
+        ));
+        return quote!(
+            if let Some(false) = #cond {
+                ::core::panicking::panic_nounwind(#msg);
+            }
+        );
+    }
+    quote!(
+        if ::core::ub_checks::check_library_ub() {
+            if !(#cond) {
+                ::core::panicking::panic_nounwind(#msg);
+            }
+        }
+    )
+}
+
+/// Generate an expression that evaluates `expr`, of type `Option<_>`, at runtime, and that is
+/// `None` during const evaluation.
 ///
-/// TODO: At runtime the `requires` should become an assert unsafe precondition.
-pub(crate) fn requires(_attr: TokenStream, item: TokenStream) -> TokenStream {
+/// `expr` is wrapped in a closure that is not called during const evaluation, so it may call
+/// functions that are not `const`, and the values it captures by move are only dropped at runtime.
+fn runtime_only(expr: TokenStream2) -> TokenStream2 {
+    quote!({
+        #[inline]
+        fn __safety_runtime<R, F: FnOnce() -> Option<R>>(f: F) -> Option<R> {
+            f()
+        }
+        #[inline]
+        const fn __safety_comptime<R, F: FnOnce() -> Option<R>>(f: F) -> Option<R> {
+            ::core::mem::forget(f);
+            None
+        }
+        ::core::intrinsics::const_eval_select((|| #expr,), __safety_comptime, __safety_runtime)
+    })
+}
+
+/// At runtime, a crate only needs `core::ub_checks` to check contracts and run harnesses.
+pub(crate) fn verify_lib() -> TokenStream {
+    quote!(
+        #[allow(unused_imports)]
+        use ::core::ub_checks;
+    ).into()
+}
+
+/// Ghost state is only meaningful to verification tools, so ghost statements are erased at
+/// runtime.
+pub(crate) fn ghost(_stmt: Stmt) -> TokenStream {
+    TokenStream::new()
+}
+
+/// At runtime, `invariant` generates an implementation of `core::ub_checks::Invariant`, which can
+/// be checked with `core::ub_checks::assert_invariant`.
+pub(crate) fn invariant(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let expr = parse_macro_input!(attr as Expr);
+    let type_item = parse_macro_input!(item as DeriveInput);
+    let name = &type_item.ident;
+    let (impl_generics, ty_generics, where_clause) = type_item.generics.split_for_impl();
+    quote!(
+        #type_item
+
+        impl #impl_generics ::core::ub_checks::Invariant for #name #ty_generics #where_clause {
+            #[inline]
+            fn is_safe(&self) -> bool {
+                #expr
+            }
+        }
+    )
+    .into()
+}
+
+/// Frame conditions are only meaningful to verification tools, so `modifies` is a no-op.
+pub(crate) fn modifies(_attr: TokenStream, item: TokenStream) -> TokenStream {
     item
 }
 
-/// For now, runtime requires is a no-op.
+/// Number of times a harness is executed when it runs as a unit test.
+const HARNESS_ITERATIONS: usize = 100;
+
+/// At runtime, a harness becomes a unit test that runs its body `HARNESS_ITERATIONS` times, with
+/// `core::ub_checks::any` generating pseudo-random values.
+pub(crate) fn harness(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    unit_test(parse_macro_input!(item as ItemFn)).into()
+}
+
+/// At runtime, a contract harness becomes a unit test, same as `harness`.
+///
+/// Verification tools assume the preconditions of the function under verification, whereas they
+/// are checked at runtime, so the harness must only generate inputs that satisfy them.
+pub(crate) fn proof_for_contract(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    unit_test(parse_macro_input!(item as ItemFn)).into()
+}
+
+/// At runtime, a harness that is expected to fail becomes an ignored unit test that is expected to
+/// panic.
 ///
-/// TODO: At runtime the `ensures` should become an assert as well.
-pub(crate) fn ensures(_attr: TokenStream, item: TokenStream) -> TokenStream {
+/// Precondition checks panic without unwinding, which aborts the test process instead of failing
+/// the test, and some failures, such as reads of uninitialized memory, are not detected at all.
+pub(crate) fn should_panic(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    let fn_item = parse_macro_input!(item as ItemFn);
+    quote!(
+        #[ignore = "the expected failure is only reported by verification tools"]
+        #[should_panic]
+        #fn_item
+    ).into()
+}
+
+/// Turn a harness into a unit test.
+///
+/// The generator used by `core::ub_checks::any` is seeded from the name of the harness, so that
+/// failures can be reproduced.
+fn unit_test(fn_item: ItemFn) -> TokenStream2 {
+    let ItemFn { attrs, vis, sig, block } = fn_item;
+    if !sig.inputs.is_empty() || !sig.generics.params.is_empty() {
+        abort!(sig, "harnesses cannot have arguments or generic parameters");
+    }
+    let seed = harness_seed(&sig.ident.to_string());
+    quote!(
+        #(#attrs)*
+        #[test]
+        #vis #sig {
+            #[allow(unused_mut)]
+            let mut __safety_harness = || #block;
+            ::core::ub_checks::set_any_seed(#seed as usize);
+            for _ in 0..#HARNESS_ITERATIONS {
+                __safety_harness();
+            }
+        }
+    )
+}
+
+/// FNV-1a hash of the name of a harness.
+fn harness_seed(name: &str) -> u64 {
+    name.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// Without a verification tool there is nothing to stub, so `stub_verified` is a no-op.
+pub(crate) fn stub_verified(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    item
+}
+
+/// Loop contracts are only used by verification tools, so `loop_invariant` is a no-op.
+pub(crate) fn loop_invariant(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    item
+}
+
+/// Loop contracts are only used by verification tools, so `loop_modifies` is a no-op.
+pub(crate) fn loop_modifies(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    item
+}
+
+/// Loop contracts are only used by verification tools, so `decreases` is a no-op.
+pub(crate) fn decreases(_attr: TokenStream, item: TokenStream) -> TokenStream {
     item
 }
+
+/// At runtime, `forall` is a bounded loop over the quantifier range.
+pub(crate) fn forall(quantifier: Quantifier) -> TokenStream {
+    let Quantifier { var, lower, upper, predicate } = quantifier;
+    quote!(((#lower)..(#upper)).all(|#var| #predicate)).into()
+}
+
+/// At runtime, `exists` is a bounded loop over the quantifier range.
+pub(crate) fn exists(quantifier: Quantifier) -> TokenStream {
+    let Quantifier { var, lower, upper, predicate } = quantifier;
+    quote!(((#lower)..(#upper)).any(|#var| #predicate)).into()
+}
@@ -0,0 +1,108 @@
+//! Tests of the contract checks generated without a verification tool.
+//!
+//! A violated contract aborts the process, so each violation is checked by running the test that
+//! triggers it in a child process.
+
+#![feature(const_eval_select, core_intrinsics, panic_internals, proc_macro_hygiene, ub_checks)]
+#![allow(internal_features)]
+
+use std::cell::Cell;
+use std::env;
+use std::process::Command;
+
+use safety::{ensures, ghost, requires};
+
+/// Environment variable set in the child process that is expected to abort.
+const VIOLATE_VAR: &str = "SAFETY_TEST_VIOLATE";
+
+/// Runs the test `name` in a child process, where `violate` is true, and checks that it aborts
+/// with `msg`.
+fn assert_aborts(name: &str, msg: &str) {
+    let output = Command::new(env::current_exe().unwrap())
+        .args([name, "--exact", "--nocapture", "--test-threads=1"])
+        .env(VIOLATE_VAR, "1")
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success(), "`{name}` did not abort");
+    assert!(stderr.contains(msg), "`{name}` did not report `{msg}`:\n{stderr}");
+}
+
+fn violate() -> bool {
+    env::var_os(VIOLATE_VAR).is_some()
+}
+
+#[requires(divisor != 0)]
+const fn div(dividend: u32, divisor: u32) -> u32 {
+    dividend / divisor
+}
+
+#[requires(value < 10)]
+#[ensures(|result| *result > value)]
+const fn succ(value: u32) -> u32 {
+    if value == 0 {
+        return 1;
+    }
+    // Wrong on purpose for `5`, so the postcondition can be violated.
+    if value == 5 { value } else { value + 1 }
+}
+
+#[test]
+fn const_fn_in_const_context() {
+    // Contracts are not checked during const evaluation, so they do not need to be const.
+    const QUOTIENT: u32 = div(6, 3);
+    const SUCC: u32 = succ(0);
+    assert_eq!((QUOTIENT, SUCC), (2, 1));
+}
+
+#[test]
+fn const_fn_requires() {
+    assert_eq!(div(6, 3), 2);
+    if violate() {
+        div(1, std::hint::black_box(0));
+    } else {
+        assert_aborts("const_fn_requires", "unsafe precondition(s) violated: divisor != 0");
+    }
+}
+
+#[test]
+fn const_fn_ensures() {
+    assert_eq!(succ(0), 1);
+    assert_eq!(succ(1), 2);
+    if violate() {
+        succ(std::hint::black_box(5));
+    } else {
+        assert_aborts("const_fn_ensures", "safety postcondition(s) violated");
+    }
+}
+
+#[ensures(|_| stack.len() == old(stack.len()) + 1)]
+#[ensures(|_| stack[..stack.len() - 1] == old(stack.clone())[..])]
+fn push(stack: &mut Vec<u32>, value: u32) {
+    // Wrong on purpose for `0`, so the postconditions can be violated.
+    if value != 0 {
+        stack.push(value);
+    }
+}
+
+#[test]
+fn old_snapshots() {
+    let mut stack = vec![1, 2];
+    push(&mut stack, 3);
+    assert_eq!(stack, [1, 2, 3]);
+    if violate() {
+        push(&mut stack, std::hint::black_box(0));
+    } else {
+        assert_aborts("old_snapshots", "safety postcondition(s) violated");
+    }
+}
+
+#[test]
+fn ghost_statements_are_erased() {
+    let count = Cell::new(0);
+    #[ghost]
+    let _ghost_count = count.replace(1);
+    #[ghost]
+    count.set(2);
+    assert_eq!(count.get(), 0);
+}
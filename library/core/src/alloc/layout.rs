@@ -4,13 +4,10 @@
 // collections, resulting in having to optimize down excess IR multiple times.
 // Your performance intuition is useless. Run perf.
 
-use safety::requires;
+use safety::{ensures, requires};
 use crate::error::Error;
 use crate::ptr::{Alignment, NonNull};
-use crate::{assert_unsafe_precondition, cmp, fmt, mem};
-
-#[cfg(kani)]
-use crate::kani;
+use crate::{assert_unsafe_precondition, cmp, fmt, mem, ub_checks};
 
 // While this function is used in one place and its implementation
 // could be inlined, the previous attempts to do so made rustc
@@ -220,6 +217,8 @@ impl Layout {
     #[unstable(feature = "layout_for_ptr", issue = "69835")]
     #[rustc_const_unstable(feature = "const_alloc_layout", issue = "67521")]
     #[must_use]
+    #[requires(ub_checks::has_valid_metadata(t))]
+    #[ensures(|result| result.size() % result.align() == 0)]
     pub const unsafe fn for_value_raw<T: ?Sized>(t: *const T) -> Self {
         // SAFETY: we pass along the prerequisites of these functions to the caller
         let (size, align) = unsafe { (mem::size_of_val_raw(t), mem::align_of_val_raw(t)) };
@@ -283,6 +282,8 @@ impl Layout {
     #[must_use = "this returns the padding needed, \
                   without modifying the `Layout`"]
     #[inline]
+    #[ensures(|padding| !align.is_power_of_two()
+        || (*padding < align && (self.size() + *padding) % align == 0))]
     pub const fn padding_needed_for(&self, align: usize) -> usize {
         let len = self.size();
 
@@ -319,6 +320,10 @@ impl Layout {
     #[must_use = "this returns a new `Layout`, \
                   without modifying the original"]
     #[inline]
+    #[ensures(|result| result.align() == self.align()
+        && result.size() == self.size() + self.padding_needed_for(self.align())
+        && result.size() % result.align() == 0
+        && result.size() <= isize::MAX as usize)]
     pub const fn pad_to_align(&self) -> Layout {
         let pad = self.padding_needed_for(self.align());
         // This cannot overflow. Quoting from the invariant of Layout:
@@ -341,6 +346,15 @@ impl Layout {
     /// On arithmetic overflow, returns `LayoutError`.
     #[unstable(feature = "alloc_layout_extra", issue = "55724")]
     #[inline]
+    #[ensures(|result| match result {
+        // Elements are `padded_size` bytes apart, which is enough to align each of them.
+        Ok((layout, padded_size)) => *padded_size == self.pad_to_align().size()
+            && n.checked_mul(*padded_size) == Some(layout.size())
+            && layout.align() == self.align()
+            && layout.size() <= isize::MAX as usize,
+        Err(_) => self.pad_to_align().size().checked_mul(n)
+            .is_none_or(|size| Layout::from_size_align(size, self.align()).is_err()),
+    })]
     pub fn repeat(&self, n: usize) -> Result<(Self, usize), LayoutError> {
         // This cannot overflow. Quoting from the invariant of Layout:
         // > `size`, when rounded up to the nearest multiple of `align`,
@@ -401,6 +415,20 @@ impl Layout {
     /// ```
     #[stable(feature = "alloc_layout_manipulation", since = "1.44.0")]
     #[inline]
+    #[ensures(|result| match result {
+        // `next` starts at the first offset after `self` that is aligned for it.
+        Ok((layout, offset)) => *offset >= self.size()
+            && *offset - self.size() < next.align()
+            && *offset % next.align() == 0
+            && layout.size() == *offset + next.size()
+            && layout.align() == cmp::max(self.align(), next.align())
+            && layout.size() <= isize::MAX as usize,
+        Err(_) => (self.size() + self.padding_needed_for(next.align()))
+            .checked_add(next.size())
+            .is_none_or(|size| {
+                Layout::from_size_align(size, cmp::max(self.align(), next.align())).is_err()
+            }),
+    })]
     pub fn extend(&self, next: Self) -> Result<(Self, usize), LayoutError> {
         let new_align = cmp::max(self.align, next.align);
         let pad = self.padding_needed_for(next.align());
@@ -427,6 +455,13 @@ impl Layout {
     /// On arithmetic overflow, returns `LayoutError`.
     #[unstable(feature = "alloc_layout_extra", issue = "55724")]
     #[inline]
+    #[ensures(|result| match result {
+        Ok(layout) => self.size().checked_mul(n) == Some(layout.size())
+            && layout.align() == self.align()
+            && layout.size() <= isize::MAX as usize,
+        Err(_) => self.size().checked_mul(n)
+            .is_none_or(|size| Layout::from_size_align(size, self.align()).is_err()),
+    })]
     pub fn repeat_packed(&self, n: usize) -> Result<Self, LayoutError> {
         let size = self.size().checked_mul(n).ok_or(LayoutError)?;
         // The safe constructor is called here to enforce the isize size limit.
@@ -441,6 +476,13 @@ impl Layout {
     /// On arithmetic overflow, returns `LayoutError`.
     #[unstable(feature = "alloc_layout_extra", issue = "55724")]
     #[inline]
+    #[ensures(|result| match result {
+        Ok(layout) => self.size().checked_add(next.size()) == Some(layout.size())
+            && layout.align() == self.align()
+            && layout.size() <= isize::MAX as usize,
+        Err(_) => self.size().checked_add(next.size())
+            .is_none_or(|size| Layout::from_size_align(size, self.align()).is_err()),
+    })]
     pub fn extend_packed(&self, next: Self) -> Result<Self, LayoutError> {
         let new_size = self.size().checked_add(next.size()).ok_or(LayoutError)?;
         // The safe constructor is called here to enforce the isize size limit.
@@ -454,6 +496,13 @@ impl Layout {
     #[stable(feature = "alloc_layout_manipulation", since = "1.44.0")]
     #[rustc_const_unstable(feature = "const_alloc_layout", issue = "67521")]
     #[inline]
+    #[ensures(|result| match result {
+        Ok(layout) => mem::size_of::<T>().checked_mul(n) == Some(layout.size())
+            && layout.align() == mem::align_of::<T>()
+            && layout.size() <= isize::MAX as usize,
+        Err(_) => mem::size_of::<T>().checked_mul(n)
+            .is_none_or(|size| Layout::from_size_align(size, mem::align_of::<T>()).is_err()),
+    })]
     pub const fn array<T>(n: usize) -> Result<Self, LayoutError> {
         // Reduce the amount of code we need to monomorphize per `T`.
         return inner(mem::size_of::<T>(), Alignment::of::<T>(), n);
@@ -515,20 +564,7 @@ impl fmt::Display for LayoutError {
     }
 }
 
+// The harnesses only use the public API, so `coretests` also runs them as unit tests.
 #[cfg(kani)]
 #[unstable(feature="kani", issue="none")]
-mod verify {
-    use super::*;
-
-    #[kani::proof_for_contract(Layout::from_size_align_unchecked)]
-    pub fn check_from_size_align_unchecked() {
-        let s = kani::any::<usize>();
-        let a = kani::any::<usize>();
-
-        unsafe {
-            let layout = Layout::from_size_align_unchecked(s, a);
-            assert_eq!(layout.size(), s);
-            assert_eq!(layout.align(), a);
-        }
-    }
-}
+mod verify;
@@ -0,0 +1,135 @@
+//! Harnesses for `Layout`.
+
+use core::alloc::Layout;
+use core::ub_checks;
+use core::{fmt, mem};
+
+/// Returns an arbitrary power of two.
+fn any_align() -> usize {
+    1 << ub_checks::any_in(0..usize::BITS)
+}
+
+/// Returns an arbitrary valid layout.
+fn any_layout() -> Layout {
+    let align = any_align();
+    // The size must not exceed `isize::MAX` once rounded up to a multiple of `align`.
+    let size = ub_checks::any_in(0..=isize::MAX as usize - (align - 1));
+    Layout::from_size_align(size, align).unwrap()
+}
+
+// pub const unsafe fn from_size_align_unchecked(size: usize, align: usize) -> Self
+#[safety::proof_for_contract(Layout::from_size_align_unchecked)]
+pub fn check_from_size_align_unchecked() {
+    let layout = any_layout();
+
+    unsafe {
+        let unchecked = Layout::from_size_align_unchecked(layout.size(), layout.align());
+        assert_eq!(unchecked, layout);
+    }
+}
+
+// pub const fn padding_needed_for(&self, align: usize) -> usize
+#[safety::proof_for_contract(Layout::padding_needed_for)]
+pub fn check_padding_needed_for() {
+    let layout = any_layout();
+    let align = if ub_checks::any() { any_align() } else { ub_checks::any() };
+    let _ = layout.padding_needed_for(align);
+}
+
+// pub const fn pad_to_align(&self) -> Layout
+#[safety::proof_for_contract(Layout::pad_to_align)]
+pub fn check_pad_to_align() {
+    let layout = any_layout();
+    let _ = layout.pad_to_align();
+}
+
+// pub fn repeat(&self, n: usize) -> Result<(Self, usize), LayoutError>
+#[safety::proof_for_contract(Layout::repeat)]
+pub fn check_repeat() {
+    let layout = any_layout();
+    let _ = layout.repeat(ub_checks::any());
+}
+
+// pub fn repeat_packed(&self, n: usize) -> Result<Self, LayoutError>
+#[safety::proof_for_contract(Layout::repeat_packed)]
+pub fn check_repeat_packed() {
+    let layout = any_layout();
+    let _ = layout.repeat_packed(ub_checks::any());
+}
+
+// pub fn extend(&self, next: Self) -> Result<(Self, usize), LayoutError>
+#[safety::proof_for_contract(Layout::extend)]
+pub fn check_extend() {
+    let layout = any_layout();
+    let _ = layout.extend(any_layout());
+}
+
+// pub fn extend_packed(&self, next: Self) -> Result<Self, LayoutError>
+#[safety::proof_for_contract(Layout::extend_packed)]
+pub fn check_extend_packed() {
+    let layout = any_layout();
+    let _ = layout.extend_packed(any_layout());
+}
+
+/// The result of `extend` followed by `pad_to_align` is the layout of a `repr(C)` struct.
+#[safety::harness]
+pub fn check_extend_repr_c() {
+    #[repr(C)]
+    struct ReprC {
+        byte: u8,
+        word: u64,
+        half: u16,
+    }
+
+    let (layout, byte) = Layout::new::<()>().extend(Layout::new::<u8>()).unwrap();
+    let (layout, word) = layout.extend(Layout::new::<u64>()).unwrap();
+    let (layout, half) = layout.extend(Layout::new::<u16>()).unwrap();
+    assert_eq!(layout.pad_to_align(), Layout::new::<ReprC>());
+    assert_eq!([byte, word, half], [
+        mem::offset_of!(ReprC, byte),
+        mem::offset_of!(ReprC, word),
+        mem::offset_of!(ReprC, half),
+    ]);
+}
+
+macro_rules! generate_array_harness {
+    ($module:ident, $ty:ty) => {
+        mod $module {
+            use super::*;
+
+            // pub const fn array<T>(n: usize) -> Result<Self, LayoutError>
+            #[safety::proof_for_contract(Layout::array::<$ty>)]
+            pub fn check_array() {
+                let _ = Layout::array::<$ty>(ub_checks::any());
+            }
+        }
+    };
+}
+
+generate_array_harness!(check_unit, ());
+generate_array_harness!(check_u8, u8);
+generate_array_harness!(check_u64, u64);
+generate_array_harness!(check_u16_array, [u16; 3]);
+
+// pub const unsafe fn for_value_raw<T: ?Sized>(t: *const T) -> Self
+#[safety::proof_for_contract(Layout::for_value_raw)]
+pub fn check_for_value_raw_sized() {
+    let value: u128 = ub_checks::any();
+    let layout = unsafe { Layout::for_value_raw(&value) };
+    assert_eq!(layout, Layout::new::<u128>());
+}
+
+#[safety::proof_for_contract(Layout::for_value_raw)]
+pub fn check_for_value_raw_slice() {
+    let array: [u32; 4] = ub_checks::any();
+    let slice = &array[ub_checks::any_in(0..=4usize)..];
+    let layout = unsafe { Layout::for_value_raw(slice) };
+    assert_eq!(layout, Layout::array::<u32>(slice.len()).unwrap());
+}
+
+#[safety::proof_for_contract(Layout::for_value_raw)]
+pub fn check_for_value_raw_dyn() {
+    let value: [u16; 3] = ub_checks::any();
+    let layout = unsafe { Layout::for_value_raw(&value as &dyn fmt::Debug) };
+    assert_eq!(layout, Layout::new::<[u16; 3]>());
+}
@@ -427,3 +427,33 @@ impl<T: fmt::Debug, const N: usize> fmt::Debug for IntoIter<T, N> {
         f.debug_tuple("IntoIter").field(&self.as_slice()).finish()
     }
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+    use crate::ub_checks;
+
+    macro_rules! generate_into_iter_harness {
+        ($harness:ident, $ty:ty) => {
+            // fn into_iter(self) -> Self::IntoIter
+            #[safety::harness]
+            fn $harness() {
+                let array: [$ty; 4] = ub_checks::any();
+                let iter = array.into_iter();
+                let alive = ptr::slice_from_raw_parts(
+                    iter.data.as_ptr().cast::<$ty>().wrapping_add(iter.alive.start()),
+                    iter.alive.len(),
+                );
+                // The elements that were not yielded yet are initialized.
+                assert!(ub_checks::is_valid_value(alive));
+                assert_eq!(iter.as_slice(), &array[..]);
+            }
+        };
+    }
+
+    generate_into_iter_harness!(check_into_iter_unit, ());
+    generate_into_iter_harness!(check_into_iter_bool, bool);
+    generate_into_iter_harness!(check_into_iter_char, char);
+    generate_into_iter_harness!(check_into_iter_u128, u128);
+}
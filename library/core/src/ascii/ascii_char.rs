@@ -628,13 +628,13 @@ mod verify {
     use super::*;
     use AsciiChar;
 
-    #[kani::proof_for_contract(AsciiChar::from_u8)]
+    #[safety::proof_for_contract(AsciiChar::from_u8)]
     fn check_from_u8() {
         let b: u8 = kani::any();
         AsciiChar::from_u8(b);
     }
 
-    #[kani::proof_for_contract(AsciiChar::from_u8_unchecked)]
+    #[safety::proof_for_contract(AsciiChar::from_u8_unchecked)]
     fn check_from_u8_unchecked() {
         let b: u8 = kani::any();
         unsafe { AsciiChar::from_u8_unchecked(b) };
@@ -8,9 +8,6 @@ use crate::mem::transmute;
 use crate::str::FromStr;
 use crate::ub_checks::assert_unsafe_precondition;
 
-#[cfg(kani)]
-use crate::kani;
-
 /// Converts a `u32` to a `char`. See [`char::from_u32`].
 #[must_use]
 #[inline]
@@ -235,6 +232,10 @@ impl FromStr for char {
 }
 
 #[inline]
+#[ensures(|result| match result {
+    Ok(c) => *c as u32 == i && i <= char::MAX as u32 && !(0xD800..=0xDFFF).contains(&i),
+    Err(_) => i > char::MAX as u32 || (0xD800..=0xDFFF).contains(&i),
+})]
 const fn char_try_from_u32(i: u32) -> Result<char, CharTryFromError> {
     // This is an optimized version of the check
     // (i > MAX as u32) || (i >= 0xD800 && i <= 0xDFFF),
@@ -301,10 +302,17 @@ pub(super) const fn from_digit(num: u32, radix: u32) -> Option<char> {
 #[unstable(feature="kani", issue="none")]
 mod verify {
     use super::*;
+    use crate::ub_checks;
 
-    #[kani::proof_for_contract(from_u32_unchecked)]
+    #[safety::proof_for_contract(from_u32_unchecked)]
     fn check_from_u32_unchecked() {
-        let i: u32 = kani::any();
+        let i: u32 = ub_checks::any();
         unsafe { from_u32_unchecked(i) };
     }
+
+    #[safety::proof_for_contract(char_try_from_u32)]
+    fn check_char_try_from_u32() {
+        let i: u32 = ub_checks::any();
+        let _ = char_try_from_u32(i);
+    }
 }
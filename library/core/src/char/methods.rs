@@ -1847,13 +1847,13 @@ mod verify {
         c.as_ascii()
     }
 
-    #[kani::proof_for_contract(as_ascii_clone)]
+    #[safety::proof_for_contract(as_ascii_clone)]
     fn check_as_ascii_ascii_char() {
         let ascii: char = kani::any_where(|c : &char| c.is_ascii());
         as_ascii_clone(&ascii);
     }
 
-    #[kani::proof_for_contract(as_ascii_clone)]
+    #[safety::proof_for_contract(as_ascii_clone)]
     fn check_as_ascii_non_ascii_char() {
         let non_ascii: char = kani::any_where(|c: &char| !c.is_ascii());
         as_ascii_clone(&non_ascii);
@@ -1,4 +1,7 @@
+use safety::requires;
+
 use crate::num::TryFromIntError;
+use crate::ub_checks;
 
 mod private {
     /// This trait being unreachable from outside the crate
@@ -15,6 +18,12 @@ pub trait FloatToInt<Int>: private::Sealed + Sized {
     #[unstable(feature = "convert_float_to_int", issue = "67057")]
     #[doc(hidden)]
     unsafe fn to_int_unchecked(self) -> Int;
+
+    /// Returns whether `self` is finite and representable in `Int` after truncating off its
+    /// fractional part, i.e., whether it satisfies the safety contract of `to_int_unchecked`.
+    #[unstable(feature = "convert_float_to_int", issue = "67057")]
+    #[doc(hidden)]
+    fn fits_in_int(self) -> bool;
 }
 
 macro_rules! impl_float_to_int {
@@ -25,10 +34,22 @@ macro_rules! impl_float_to_int {
             #[unstable(feature = "convert_float_to_int", issue = "67057")]
             impl FloatToInt<$Int> for $Float {
                 #[inline]
+                #[requires(ub_checks::float_fits_in::<$Int>(self))]
                 unsafe fn to_int_unchecked(self) -> $Int {
                     // SAFETY: the safety contract must be upheld by the caller.
                     unsafe { crate::intrinsics::float_to_int_unchecked(self) }
                 }
+
+                #[inline]
+                fn fits_in_int(self) -> bool {
+                    // `MIN` and `MAX + 1` are zero or powers of two, so they are exact unless
+                    // they overflow to infinity, which bounds every finite value anyway.
+                    let min = <$Int>::MIN as $Float;
+                    let max_plus_one = (<$Int>::MAX / 2 + 1) as $Float * 2.0;
+                    // If `min - 1` is not representable, it rounds to `min`, and no value lies
+                    // between the two.
+                    self.is_finite() && (min - 1.0 < self || self == min) && self < max_plus_one
+                }
             }
         )+
     }
@@ -540,3 +561,60 @@ impl_nonzero_int_try_from_nonzero_int!(i32 => u8, u16, u32, u64, u128, usize);
 impl_nonzero_int_try_from_nonzero_int!(i64 => u8, u16, u32, u64, u128, usize);
 impl_nonzero_int_try_from_nonzero_int!(i128 => u8, u16, u32, u64, u128, usize);
 impl_nonzero_int_try_from_nonzero_int!(isize => u8, u16, u32, u64, u128, usize);
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+
+    macro_rules! generate_to_int_unchecked_harnesses {
+        ($module:ident, $Float:ty) => {
+            mod $module {
+                use super::*;
+
+                generate_to_int_unchecked_harnesses!(
+                    $Float,
+                    to_u8: u8,
+                    to_u16: u16,
+                    to_u32: u32,
+                    to_u64: u64,
+                    to_u128: u128,
+                    to_usize: usize,
+                    to_i8: i8,
+                    to_i16: i16,
+                    to_i32: i32,
+                    to_i64: i64,
+                    to_i128: i128,
+                    to_isize: isize
+                );
+            }
+        };
+        ($Float:ty, $($module:ident: $Int:ty),+) => {
+            $(
+                mod $module {
+                    use super::*;
+
+                    // unsafe fn to_int_unchecked(self) -> $Int
+                    #[safety::proof_for_contract(<$Float as FloatToInt<$Int>>::to_int_unchecked)]
+                    fn check_to_int_unchecked() {
+                        let x: $Float = ub_checks::any();
+                        // In range, truncating agrees with the saturating cast.
+                        assert_eq!(unsafe { FloatToInt::<$Int>::to_int_unchecked(x) }, x as $Int);
+                    }
+
+                    // pub unsafe fn to_int_unchecked<Int>(self) -> Int
+                    #[safety::proof_for_contract(<$Float>::to_int_unchecked::<$Int>)]
+                    fn check_inherent_to_int_unchecked() {
+                        let x: $Float = ub_checks::any();
+                        assert_eq!(unsafe { x.to_int_unchecked::<$Int>() }, x as $Int);
+                    }
+                }
+            )+
+        };
+    }
+
+    generate_to_int_unchecked_harnesses!(check_f16, f16);
+    generate_to_int_unchecked_harnesses!(check_f32, f32);
+    generate_to_int_unchecked_harnesses!(check_f64, f64);
+    generate_to_int_unchecked_harnesses!(check_f128, f128);
+}
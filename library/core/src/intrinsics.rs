@@ -63,8 +63,9 @@
 )]
 #![allow(missing_docs)]
 
-use safety::requires;
+use safety::{ensures, modifies, requires};
 use crate::marker::{DiscriminantKind, Tuple};
+use crate::mem::MaybeUninit;
 use crate::{ptr, ub_checks};
 
 #[cfg(kani)]
@@ -1438,24 +1439,6 @@ extern "rust-intrinsic" {
     #[rustc_nounwind]
     pub fn offset<Ptr, Delta>(dst: Ptr, offset: Delta) -> Ptr;
 
-    /// Calculates the offset from a pointer, potentially wrapping.
-    ///
-    /// This is implemented as an intrinsic to avoid converting to and from an
-    /// integer, since the conversion inhibits certain optimizations.
-    ///
-    /// # Safety
-    ///
-    /// Unlike the `offset` intrinsic, this intrinsic does not restrict the
-    /// resulting pointer to point into or at the end of an allocated
-    /// object, and it wraps with two's complement arithmetic. The resulting
-    /// value is not necessarily valid to be used to actually access memory.
-    ///
-    /// The stabilized version of this intrinsic is [`pointer::wrapping_offset`].
-    #[must_use = "returns a new pointer rather than modifying its argument"]
-    #[rustc_const_stable(feature = "const_ptr_offset", since = "1.61.0")]
-    #[rustc_nounwind]
-    pub fn arith_offset<T>(dst: *const T, offset: isize) -> *const T;
-
     /// Masks out bits of the pointer according to a mask.
     ///
     /// Note that, unlike most intrinsics, this is safe to call;
@@ -1468,37 +1451,6 @@ extern "rust-intrinsic" {
     #[rustc_nounwind]
     pub fn ptr_mask<T>(ptr: *const T, mask: usize) -> *const T;
 
-    /// Equivalent to the appropriate `llvm.memcpy.p0i8.0i8.*` intrinsic, with
-    /// a size of `count` * `size_of::<T>()` and an alignment of
-    /// `min_align_of::<T>()`
-    ///
-    /// The volatile parameter is set to `true`, so it will not be optimized out
-    /// unless size is equal to zero.
-    ///
-    /// This intrinsic does not have a stable counterpart.
-    #[rustc_nounwind]
-    pub fn volatile_copy_nonoverlapping_memory<T>(dst: *mut T, src: *const T, count: usize);
-    /// Equivalent to the appropriate `llvm.memmove.p0i8.0i8.*` intrinsic, with
-    /// a size of `count * size_of::<T>()` and an alignment of
-    /// `min_align_of::<T>()`
-    ///
-    /// The volatile parameter is set to `true`, so it will not be optimized out
-    /// unless size is equal to zero.
-    ///
-    /// This intrinsic does not have a stable counterpart.
-    #[rustc_nounwind]
-    pub fn volatile_copy_memory<T>(dst: *mut T, src: *const T, count: usize);
-    /// Equivalent to the appropriate `llvm.memset.p0i8.*` intrinsic, with a
-    /// size of `count * size_of::<T>()` and an alignment of
-    /// `min_align_of::<T>()`.
-    ///
-    /// The volatile parameter is set to `true`, so it will not be optimized out
-    /// unless size is equal to zero.
-    ///
-    /// This intrinsic does not have a stable counterpart.
-    #[rustc_nounwind]
-    pub fn volatile_set_memory<T>(dst: *mut T, val: u8, count: usize);
-
     /// Performs a volatile load from the `src` pointer.
     ///
     /// The stabilized version of this intrinsic is [`core::ptr::read_volatile`].
@@ -1510,21 +1462,6 @@ extern "rust-intrinsic" {
     #[rustc_nounwind]
     pub fn volatile_store<T>(dst: *mut T, val: T);
 
-    /// Performs a volatile load from the `src` pointer
-    /// The pointer is not required to be aligned.
-    ///
-    /// This intrinsic does not have a stable counterpart.
-    #[rustc_nounwind]
-    #[rustc_diagnostic_item = "intrinsics_unaligned_volatile_load"]
-    pub fn unaligned_volatile_load<T>(src: *const T) -> T;
-    /// Performs a volatile store to the `dst` pointer.
-    /// The pointer is not required to be aligned.
-    ///
-    /// This intrinsic does not have a stable counterpart.
-    #[rustc_nounwind]
-    #[rustc_diagnostic_item = "intrinsics_unaligned_volatile_store"]
-    pub fn unaligned_volatile_store<T>(dst: *mut T, val: T);
-
     /// Returns the square root of an `f16`
     ///
     /// The stabilized version of this intrinsic is
@@ -2624,26 +2561,6 @@ extern "rust-intrinsic" {
     #[rustc_nounwind]
     pub fn saturating_sub<T: Copy>(a: T, b: T) -> T;
 
-    /// This is an implementation detail of [`crate::ptr::read`] and should
-    /// not be used anywhere else.  See its comments for why this exists.
-    ///
-    /// This intrinsic can *only* be called where the pointer is a local without
-    /// projections (`read_via_copy(ptr)`, not `read_via_copy(*ptr)`) so that it
-    /// trivially obeys runtime-MIR rules about derefs in operands.
-    #[rustc_const_stable(feature = "const_ptr_read", since = "1.71.0")]
-    #[rustc_nounwind]
-    pub fn read_via_copy<T>(ptr: *const T) -> T;
-
-    /// This is an implementation detail of [`crate::ptr::write`] and should
-    /// not be used anywhere else.  See its comments for why this exists.
-    ///
-    /// This intrinsic can *only* be called where the pointer is a local without
-    /// projections (`write_via_move(ptr, x)`, not `write_via_move(*ptr, x)`) so
-    /// that it trivially obeys runtime-MIR rules about derefs in operands.
-    #[rustc_const_unstable(feature = "const_ptr_write", issue = "86302")]
-    #[rustc_nounwind]
-    pub fn write_via_move<T>(ptr: *mut T, value: T);
-
     /// Returns the value of the discriminant for the variant in 'v';
     /// if `T` has no discriminant, returns `0`.
     ///
@@ -2680,16 +2597,212 @@ extern "rust-intrinsic" {
     /// in ways that are not allowed for regular writes).
     #[rustc_nounwind]
     pub fn nontemporal_store<T>(ptr: *mut T, val: T);
+}
 
-    /// See documentation of `<*const T>::offset_from` for details.
-    #[rustc_const_stable(feature = "const_ptr_offset_from", since = "1.65.0")]
-    #[rustc_nounwind]
-    pub fn ptr_offset_from<T>(ptr: *const T, base: *const T) -> isize;
+/// Calculates the offset from a pointer, potentially wrapping.
+///
+/// This is implemented as an intrinsic to avoid converting to and from an
+/// integer, since the conversion inhibits certain optimizations.
+///
+/// # Safety
+///
+/// Unlike the `offset` intrinsic, this intrinsic does not restrict the
+/// resulting pointer to point into or at the end of an allocated
+/// object, and it wraps with two's complement arithmetic. The resulting
+/// value is not necessarily valid to be used to actually access memory.
+///
+/// The stabilized version of this intrinsic is [`pointer::wrapping_offset`].
+#[unstable(feature = "core_intrinsics", issue = "none")]
+#[must_use = "returns a new pointer rather than modifying its argument"]
+#[rustc_const_stable(feature = "const_ptr_offset", since = "1.61.0")]
+#[rustc_nounwind]
+#[rustc_intrinsic]
+#[rustc_intrinsic_must_be_overridden]
+#[ensures(|result| {
+    // The offset wraps around the address space instead of being restricted to an allocation.
+    let bytes = _offset.wrapping_mul(size_of::<T>() as isize);
+    result.addr() == _dst.addr().wrapping_add_signed(bytes)
+})]
+pub const unsafe fn arith_offset<T>(_dst: *const T, _offset: isize) -> *const T {
+    unreachable!()
+}
 
-    /// See documentation of `<*const T>::sub_ptr` for details.
-    #[rustc_const_unstable(feature = "const_ptr_sub_ptr", issue = "95892")]
-    #[rustc_nounwind]
-    pub fn ptr_offset_from_unsigned<T>(ptr: *const T, base: *const T) -> usize;
+/// Equivalent to the appropriate `llvm.memcpy.p0i8.0i8.*` intrinsic, with
+/// a size of `count` * `size_of::<T>()` and an alignment of
+/// `min_align_of::<T>()`
+///
+/// The volatile parameter is set to `true`, so it will not be optimized out
+/// unless size is equal to zero.
+///
+/// This intrinsic does not have a stable counterpart.
+#[unstable(feature = "core_intrinsics", issue = "none")]
+#[rustc_nounwind]
+#[rustc_intrinsic]
+#[rustc_intrinsic_must_be_overridden]
+#[requires({
+    // The copy is untyped, so the source may be uninitialized.
+    let src = ptr::slice_from_raw_parts(_src.cast::<MaybeUninit<T>>(), _count);
+    ub_checks::is_valid_allocation_size(size_of::<T>(), _count)
+        && ub_checks::can_dereference(src)
+        && ub_checks::can_write(ptr::slice_from_raw_parts_mut(_dst, _count))
+        && ub_checks::is_nonoverlapping(
+            _src as *const (),
+            _dst as *const (),
+            size_of::<T>(),
+            _count,
+        )
+})]
+#[modifies(ptr::slice_from_raw_parts_mut(_dst, _count))]
+pub unsafe fn volatile_copy_nonoverlapping_memory<T>(_dst: *mut T, _src: *const T, _count: usize) {
+    unreachable!()
+}
+
+/// Equivalent to the appropriate `llvm.memmove.p0i8.0i8.*` intrinsic, with
+/// a size of `count * size_of::<T>()` and an alignment of
+/// `min_align_of::<T>()`
+///
+/// The volatile parameter is set to `true`, so it will not be optimized out
+/// unless size is equal to zero.
+///
+/// This intrinsic does not have a stable counterpart.
+#[unstable(feature = "core_intrinsics", issue = "none")]
+#[rustc_nounwind]
+#[rustc_intrinsic]
+#[rustc_intrinsic_must_be_overridden]
+#[requires({
+    // The copy is untyped, so the source may be uninitialized.
+    let src = ptr::slice_from_raw_parts(_src.cast::<MaybeUninit<T>>(), _count);
+    ub_checks::is_valid_allocation_size(size_of::<T>(), _count)
+        && ub_checks::can_dereference(src)
+        && ub_checks::can_write(ptr::slice_from_raw_parts_mut(_dst, _count))
+})]
+#[modifies(ptr::slice_from_raw_parts_mut(_dst, _count))]
+pub unsafe fn volatile_copy_memory<T>(_dst: *mut T, _src: *const T, _count: usize) {
+    unreachable!()
+}
+
+/// Equivalent to the appropriate `llvm.memset.p0i8.*` intrinsic, with a
+/// size of `count * size_of::<T>()` and an alignment of
+/// `min_align_of::<T>()`.
+///
+/// The volatile parameter is set to `true`, so it will not be optimized out
+/// unless size is equal to zero.
+///
+/// This intrinsic does not have a stable counterpart.
+#[unstable(feature = "core_intrinsics", issue = "none")]
+#[rustc_nounwind]
+#[rustc_intrinsic]
+#[rustc_intrinsic_must_be_overridden]
+#[requires(
+    ub_checks::is_valid_allocation_size(size_of::<T>(), _count)
+        && ub_checks::can_write(ptr::slice_from_raw_parts_mut(_dst, _count))
+)]
+#[modifies(ptr::slice_from_raw_parts_mut(_dst, _count))]
+pub unsafe fn volatile_set_memory<T>(_dst: *mut T, _val: u8, _count: usize) {
+    unreachable!()
+}
+
+/// Performs a volatile load from the `src` pointer
+/// The pointer is not required to be aligned.
+///
+/// This intrinsic does not have a stable counterpart.
+#[unstable(feature = "core_intrinsics", issue = "none")]
+#[rustc_nounwind]
+#[rustc_diagnostic_item = "intrinsics_unaligned_volatile_load"]
+#[rustc_intrinsic]
+#[rustc_intrinsic_must_be_overridden]
+#[requires(ub_checks::can_read_unaligned(_src))]
+pub unsafe fn unaligned_volatile_load<T>(_src: *const T) -> T {
+    unreachable!()
+}
+
+/// Performs a volatile store to the `dst` pointer.
+/// The pointer is not required to be aligned.
+///
+/// This intrinsic does not have a stable counterpart.
+#[unstable(feature = "core_intrinsics", issue = "none")]
+#[rustc_nounwind]
+#[rustc_diagnostic_item = "intrinsics_unaligned_volatile_store"]
+#[rustc_intrinsic]
+#[rustc_intrinsic_must_be_overridden]
+#[requires(ub_checks::can_write_unaligned(_dst))]
+#[modifies(_dst)]
+pub unsafe fn unaligned_volatile_store<T>(_dst: *mut T, _val: T) {
+    unreachable!()
+}
+
+/// This is an implementation detail of [`crate::ptr::read`] and should
+/// not be used anywhere else.  See its comments for why this exists.
+///
+/// This intrinsic can *only* be called where the pointer is a local without
+/// projections (`read_via_copy(ptr)`, not `read_via_copy(*ptr)`) so that it
+/// trivially obeys runtime-MIR rules about derefs in operands.
+#[unstable(feature = "core_intrinsics", issue = "none")]
+#[rustc_const_stable(feature = "const_ptr_read", since = "1.71.0")]
+#[rustc_nounwind]
+#[rustc_intrinsic]
+#[rustc_intrinsic_must_be_overridden]
+#[requires(ub_checks::can_dereference(_ptr))]
+pub const unsafe fn read_via_copy<T>(_ptr: *const T) -> T {
+    unreachable!()
+}
+
+/// This is an implementation detail of [`crate::ptr::write`] and should
+/// not be used anywhere else.  See its comments for why this exists.
+///
+/// This intrinsic can *only* be called where the pointer is a local without
+/// projections (`write_via_move(ptr, x)`, not `write_via_move(*ptr, x)`) so
+/// that it trivially obeys runtime-MIR rules about derefs in operands.
+#[unstable(feature = "core_intrinsics", issue = "none")]
+#[rustc_const_unstable(feature = "const_ptr_write", issue = "86302")]
+#[rustc_nounwind]
+#[rustc_intrinsic]
+#[rustc_intrinsic_must_be_overridden]
+#[requires(ub_checks::can_write(_ptr))]
+#[modifies(_ptr)]
+pub const unsafe fn write_via_move<T>(_ptr: *mut T, _value: T) {
+    unreachable!()
+}
+
+/// See documentation of `<*const T>::offset_from` for details.
+#[unstable(feature = "core_intrinsics", issue = "none")]
+#[rustc_const_stable(feature = "const_ptr_offset_from", since = "1.65.0")]
+#[rustc_nounwind]
+#[rustc_intrinsic]
+#[rustc_intrinsic_must_be_overridden]
+#[requires(
+    // Both pointers must be in bounds of the same allocated object, their distance must be a
+    // multiple of the size of `T`, and `T` must not be zero-sized.
+    size_of::<T>() != 0
+        && size_of::<T>() <= isize::MAX as usize
+        && ub_checks::same_allocation(_ptr, _base)
+        && _ptr.addr().abs_diff(_base.addr()) % size_of::<T>() == 0
+)]
+#[ensures(|result| {
+    let bytes = result.wrapping_mul(size_of::<T>() as isize);
+    bytes == _ptr.addr().wrapping_sub(_base.addr()) as isize
+})]
+pub const unsafe fn ptr_offset_from<T>(_ptr: *const T, _base: *const T) -> isize {
+    unreachable!()
+}
+
+/// See documentation of `<*const T>::sub_ptr` for details.
+#[unstable(feature = "core_intrinsics", issue = "none")]
+#[rustc_const_unstable(feature = "const_ptr_sub_ptr", issue = "95892")]
+#[rustc_nounwind]
+#[rustc_intrinsic]
+#[rustc_intrinsic_must_be_overridden]
+#[requires(
+    // Same as `ptr_offset_from`, and `ptr` must not be below `base`.
+    size_of::<T>() != 0
+        && size_of::<T>() <= isize::MAX as usize
+        && ub_checks::same_allocation(_ptr, _base)
+        && _ptr.addr() >= _base.addr()
+        && (_ptr.addr() - _base.addr()) % size_of::<T>() == 0
+)]
+#[ensures(|result| result.wrapping_mul(size_of::<T>()) == _ptr.addr() - _base.addr())]
+pub const unsafe fn ptr_offset_from_unsigned<T>(_ptr: *const T, _base: *const T) -> usize {
+    unreachable!()
 }
 
 /// See documentation of `<*const T>::guaranteed_eq` for details.
@@ -2735,24 +2848,6 @@ extern "rust-intrinsic" {
     #[rustc_nounwind]
     pub fn raw_eq<T>(a: &T, b: &T) -> bool;
 
-    /// Lexicographically compare `[left, left + bytes)` and `[right, right + bytes)`
-    /// as unsigned bytes, returning negative if `left` is less, zero if all the
-    /// bytes match, or positive if `right` is greater.
-    ///
-    /// This underlies things like `<[u8]>::cmp`, and will usually lower to `memcmp`.
-    ///
-    /// # Safety
-    ///
-    /// `left` and `right` must each be [valid] for reads of `bytes` bytes.
-    ///
-    /// Note that this applies to the whole range, not just until the first byte
-    /// that differs.  That allows optimizations that can read in large chunks.
-    ///
-    /// [valid]: crate::ptr#safety
-    #[rustc_const_unstable(feature = "const_intrinsic_compare_bytes", issue = "none")]
-    #[rustc_nounwind]
-    pub fn compare_bytes(left: *const u8, right: *const u8, bytes: usize) -> i32;
-
     /// See documentation of [`std::hint::black_box`] for details.
     ///
     /// [`std::hint::black_box`]: crate::hint::black_box
@@ -2762,6 +2857,34 @@ extern "rust-intrinsic" {
     pub fn black_box<T>(dummy: T) -> T;
 }
 
+/// Lexicographically compare `[left, left + bytes)` and `[right, right + bytes)`
+/// as unsigned bytes, returning negative if `left` is less, zero if all the
+/// bytes match, or positive if `right` is greater.
+///
+/// This underlies things like `<[u8]>::cmp`, and will usually lower to `memcmp`.
+///
+/// # Safety
+///
+/// `left` and `right` must each be [valid] for reads of `bytes` bytes.
+///
+/// Note that this applies to the whole range, not just until the first byte
+/// that differs.  That allows optimizations that can read in large chunks.
+///
+/// [valid]: crate::ptr#safety
+#[unstable(feature = "core_intrinsics", issue = "none")]
+#[rustc_const_unstable(feature = "const_intrinsic_compare_bytes", issue = "none")]
+#[rustc_nounwind]
+#[rustc_intrinsic]
+#[rustc_intrinsic_must_be_overridden]
+#[requires(
+    ub_checks::is_valid_allocation_size(1, _bytes)
+        && ub_checks::can_dereference(ptr::slice_from_raw_parts(_left, _bytes))
+        && ub_checks::can_dereference(ptr::slice_from_raw_parts(_right, _bytes))
+)]
+pub const unsafe fn compare_bytes(_left: *const u8, _right: *const u8, _bytes: usize) -> i32 {
+    unreachable!()
+}
+
 /// Selects which function to call depending on the context.
 ///
 /// If this function is evaluated at compile-time, then a call to this
@@ -2930,8 +3053,8 @@ pub const fn is_val_statically_known<T: Copy>(_arg: T) -> bool {
 #[rustc_intrinsic]
 // This has fallback `const fn` MIR, so shouldn't need stability, see #122652
 #[rustc_const_unstable(feature = "const_typed_swap", issue = "none")]
-#[cfg_attr(kani, kani::modifies(x))]
-#[cfg_attr(kani, kani::modifies(y))]
+#[modifies(x)]
+#[modifies(y)]
 #[requires(ub_checks::can_dereference(x) && ub_checks::can_write(x))]
 #[requires(ub_checks::can_dereference(y) && ub_checks::can_write(y))]
 #[requires(x.addr() != y.addr() || core::mem::size_of::<T>() == 0)]
@@ -3010,6 +3133,10 @@ pub const unsafe fn const_deallocate(_ptr: *mut u8, _size: usize, _align: usize)
 #[unstable(feature = "core_intrinsics", issue = "none")]
 #[rustc_intrinsic]
 #[rustc_intrinsic_must_be_overridden]
+// Whether `ptr` points to a vtable cannot be expressed with the memory predicates, so the
+// contract only covers the layout of its entries.
+#[requires(!_ptr.is_null() && _ptr.is_aligned_to(min_align_of::<usize>()))]
+#[ensures(|result| *result <= isize::MAX as usize)]
 pub unsafe fn vtable_size(_ptr: *const ()) -> usize {
     unreachable!()
 }
@@ -3023,6 +3150,8 @@ pub unsafe fn vtable_size(_ptr: *const ()) -> usize {
 #[unstable(feature = "core_intrinsics", issue = "none")]
 #[rustc_intrinsic]
 #[rustc_intrinsic_must_be_overridden]
+#[requires(!_ptr.is_null() && _ptr.is_aligned_to(min_align_of::<usize>()))]
+#[ensures(|result| result.is_power_of_two())]
 pub unsafe fn vtable_align(_ptr: *const ()) -> usize {
     unreachable!()
 }
@@ -3300,6 +3429,15 @@ pub const fn ptr_metadata<P: ptr::Pointee<Metadata = M> + ?Sized, M>(_ptr: *cons
 #[inline(always)]
 #[cfg_attr(miri, track_caller)] // even without panics, this helps for Miri backtraces
 #[rustc_diagnostic_item = "ptr_copy_nonoverlapping"]
+#[requires({
+    // The copy is untyped, so the source may be uninitialized.
+    let src_slice = ptr::slice_from_raw_parts(src.cast::<MaybeUninit<T>>(), count);
+    ub_checks::is_valid_allocation_size(size_of::<T>(), count)
+        && ub_checks::can_dereference(src_slice)
+        && ub_checks::can_write(ptr::slice_from_raw_parts_mut(dst, count))
+        && ub_checks::is_nonoverlapping(src as *const (), dst as *const (), size_of::<T>(), count)
+})]
+#[modifies(ptr::slice_from_raw_parts_mut(dst, count))]
 pub const unsafe fn copy_nonoverlapping<T>(src: *const T, dst: *mut T, count: usize) {
     extern "rust-intrinsic" {
         #[rustc_const_unstable(feature = "const_intrinsic_copy", issue = "80697")]
@@ -3402,6 +3540,14 @@ pub const unsafe fn copy_nonoverlapping<T>(src: *const T, dst: *mut T, count: us
 #[inline(always)]
 #[cfg_attr(miri, track_caller)] // even without panics, this helps for Miri backtraces
 #[rustc_diagnostic_item = "ptr_copy"]
+#[requires({
+    // The copy is untyped, so the source may be uninitialized.
+    let src_slice = ptr::slice_from_raw_parts(src.cast::<MaybeUninit<T>>(), count);
+    ub_checks::is_valid_allocation_size(size_of::<T>(), count)
+        && ub_checks::can_dereference(src_slice)
+        && ub_checks::can_write(ptr::slice_from_raw_parts_mut(dst, count))
+})]
+#[modifies(ptr::slice_from_raw_parts_mut(dst, count))]
 pub const unsafe fn copy<T>(src: *const T, dst: *mut T, count: usize) {
     extern "rust-intrinsic" {
         #[rustc_const_unstable(feature = "const_intrinsic_copy", issue = "80697")]
@@ -3482,6 +3628,11 @@ pub const unsafe fn copy<T>(src: *const T, dst: *mut T, count: usize) {
 #[inline(always)]
 #[cfg_attr(miri, track_caller)] // even without panics, this helps for Miri backtraces
 #[rustc_diagnostic_item = "ptr_write_bytes"]
+#[requires(
+    ub_checks::is_valid_allocation_size(size_of::<T>(), count)
+        && ub_checks::can_write(ptr::slice_from_raw_parts_mut(dst, count))
+)]
+#[modifies(ptr::slice_from_raw_parts_mut(dst, count))]
 pub const unsafe fn write_bytes<T>(dst: *mut T, val: u8, count: usize) {
     extern "rust-intrinsic" {
         #[rustc_const_unstable(feature = "const_ptr_write", issue = "86302")]
@@ -3530,19 +3681,22 @@ pub(crate) const fn miri_promise_symbolic_alignment(ptr: *const (), align: usize
 mod verify {
     use core::{cmp, fmt};
     use super::*;
-    use crate::kani;
+    use crate::ptr::arbitrary::{
+        any_byte_offset, any_count, any_element_index, any_index, any_start, ARRAY_LEN,
+    };
+    use crate::{kani, slice};
 
-    #[kani::proof_for_contract(typed_swap)]
+    #[safety::proof_for_contract(typed_swap)]
     pub fn check_typed_swap_u8() {
         check_swap::<u8>()
     }
 
-    #[kani::proof_for_contract(typed_swap)]
+    #[safety::proof_for_contract(typed_swap)]
     pub fn check_typed_swap_char() {
         check_swap::<char>()
     }
 
-    #[kani::proof_for_contract(typed_swap)]
+    #[safety::proof_for_contract(typed_swap)]
     pub fn check_typed_swap_non_zero() {
         check_swap::<core::num::NonZeroI32>()
     }
@@ -3557,4 +3711,216 @@ mod verify {
         assert_eq!(y, old_x);
         assert_eq!(x, old_y);
     }
+
+    // The harnesses of the memory intrinsics run on arrays of arbitrary values, with pointers to
+    // arbitrary elements of them, and arbitrary counts that are only constrained by the contracts.
+    // Besides the contract of the intrinsic, each harness states which properties of the result
+    // it guarantees. The intrinsics without a fallback body are only checked by Kani, against the
+    // semantics it gives them, since their bodies never run.
+    macro_rules! generate_memory_harnesses {
+        ($module:ident, $ty:ty) => {
+            generate_memory_harnesses!($module, $ty, {
+                /// The result is the distance between the pointers, in elements.
+                #[safety::proof_for_contract(ptr_offset_from)]
+                fn check_ptr_offset_from() {
+                    let array: [$ty; ARRAY_LEN] = ub_checks::any();
+                    let (ptr_idx, base_idx) = (any_index(), any_index());
+                    let ptr = array.as_ptr().wrapping_add(ptr_idx);
+                    let base = array.as_ptr().wrapping_add(base_idx);
+                    let distance = unsafe { ptr_offset_from(ptr, base) };
+                    assert_eq!(distance, ptr_idx as isize - base_idx as isize);
+                }
+
+                /// The result is the distance between the pointers, in elements.
+                #[safety::proof_for_contract(ptr_offset_from_unsigned)]
+                fn check_ptr_offset_from_unsigned() {
+                    let array: [$ty; ARRAY_LEN] = ub_checks::any();
+                    let (ptr_idx, base_idx) = (any_index(), any_index());
+                    let ptr = array.as_ptr().wrapping_add(ptr_idx);
+                    let base = array.as_ptr().wrapping_add(base_idx);
+                    let distance = unsafe { ptr_offset_from_unsigned(ptr, base) };
+                    assert_eq!(distance, ptr_idx - base_idx);
+                }
+            });
+        };
+        ($module:ident, $ty:ty, { $($extra:item)* }) => {
+            mod $module {
+                use super::*;
+
+                /// This verifies the fallback body: the destination holds the elements of the
+                /// source.
+                #[safety::proof_for_contract(copy_nonoverlapping)]
+                fn check_copy_nonoverlapping() {
+                    let src: [$ty; ARRAY_LEN] = ub_checks::any();
+                    let mut dst: [$ty; ARRAY_LEN] = ub_checks::any();
+                    let count = any_count();
+                    let (src_idx, dst_idx) = (any_start(count), any_start(count));
+                    let src_ptr = src.as_ptr().wrapping_add(src_idx);
+                    let dst_ptr = dst.as_mut_ptr().wrapping_add(dst_idx);
+                    unsafe { copy_nonoverlapping(src_ptr, dst_ptr, count) };
+                    assert_eq!(dst[dst_idx..][..count], src[src_idx..][..count]);
+                }
+
+                /// This verifies the fallback body: the destination holds the elements that were
+                /// in the source before the copy, even if both ranges overlap.
+                #[safety::proof_for_contract(copy)]
+                fn check_copy() {
+                    let mut array: [$ty; ARRAY_LEN] = ub_checks::any();
+                    let old_array = array;
+                    let count = any_count();
+                    let (src_idx, dst_idx) = (any_start(count), any_start(count));
+                    let base = array.as_mut_ptr();
+                    unsafe { copy(base.wrapping_add(src_idx), base.wrapping_add(dst_idx), count) };
+                    assert_eq!(array[dst_idx..][..count], old_array[src_idx..][..count]);
+                }
+
+                /// This verifies the fallback body: every byte of the destination is `val`.
+                #[safety::proof_for_contract(write_bytes)]
+                fn check_write_bytes() {
+                    let mut array: [$ty; ARRAY_LEN] = ub_checks::any();
+                    let (idx, val, count) = (any_index(), ub_checks::any(), ub_checks::any());
+                    let dst = array.as_mut_ptr().wrapping_add(idx);
+                    unsafe { write_bytes(dst, val, count) };
+                    let len = count * size_of::<$ty>();
+                    let bytes = unsafe { slice::from_raw_parts(dst.cast::<u8>(), len) };
+                    assert!(bytes.iter().all(|byte| *byte == val));
+                }
+
+                /// The destination holds the elements of the source.
+                #[safety::proof_for_contract(volatile_copy_nonoverlapping_memory)]
+                fn check_volatile_copy_nonoverlapping_memory() {
+                    let src: [$ty; ARRAY_LEN] = ub_checks::any();
+                    let mut dst: [$ty; ARRAY_LEN] = ub_checks::any();
+                    let count = any_count();
+                    let (src_idx, dst_idx) = (any_start(count), any_start(count));
+                    let src_ptr = src.as_ptr().wrapping_add(src_idx);
+                    let dst_ptr = dst.as_mut_ptr().wrapping_add(dst_idx);
+                    unsafe { volatile_copy_nonoverlapping_memory(dst_ptr, src_ptr, count) };
+                    assert_eq!(dst[dst_idx..][..count], src[src_idx..][..count]);
+                }
+
+                /// The destination holds the elements that were in the source before the copy,
+                /// even if both ranges overlap.
+                #[safety::proof_for_contract(volatile_copy_memory)]
+                fn check_volatile_copy_memory() {
+                    let mut array: [$ty; ARRAY_LEN] = ub_checks::any();
+                    let old_array = array;
+                    let count = any_count();
+                    let (src_idx, dst_idx) = (any_start(count), any_start(count));
+                    let base = array.as_mut_ptr();
+                    let (src, dst) = (base.wrapping_add(src_idx), base.wrapping_add(dst_idx));
+                    unsafe { volatile_copy_memory(dst, src, count) };
+                    assert_eq!(array[dst_idx..][..count], old_array[src_idx..][..count]);
+                }
+
+                /// Every byte of the destination is `val`.
+                #[safety::proof_for_contract(volatile_set_memory)]
+                fn check_volatile_set_memory() {
+                    let mut array: [$ty; ARRAY_LEN] = ub_checks::any();
+                    let (idx, val, count) = (any_index(), ub_checks::any(), ub_checks::any());
+                    let dst = array.as_mut_ptr().wrapping_add(idx);
+                    unsafe { volatile_set_memory(dst, val, count) };
+                    let len = count * size_of::<$ty>();
+                    let bytes = unsafe { slice::from_raw_parts(dst.cast::<u8>(), len) };
+                    assert!(bytes.iter().all(|byte| *byte == val));
+                }
+
+                /// The loaded value is the one stored at the possibly unaligned address.
+                #[safety::proof_for_contract(unaligned_volatile_load)]
+                fn check_unaligned_volatile_load() {
+                    let array: [$ty; ARRAY_LEN] = ub_checks::any();
+                    let offset = any_byte_offset::<$ty>();
+                    let src = array.as_ptr().wrapping_byte_add(offset);
+                    let value = unsafe { unaligned_volatile_load(src) };
+                    assert_eq!(value, unsafe { src.read_unaligned() });
+                }
+
+                /// The stored value can be read back from the possibly unaligned address.
+                #[safety::proof_for_contract(unaligned_volatile_store)]
+                fn check_unaligned_volatile_store() {
+                    let mut array: [$ty; ARRAY_LEN] = ub_checks::any();
+                    let offset = any_byte_offset::<$ty>();
+                    let dst = array.as_mut_ptr().wrapping_byte_add(offset);
+                    let value: $ty = ub_checks::any();
+                    unsafe { unaligned_volatile_store(dst, value) };
+                    assert_eq!(unsafe { dst.read_unaligned() }, value);
+                }
+
+                /// The result is the element the pointer points to.
+                #[safety::proof_for_contract(read_via_copy)]
+                fn check_read_via_copy() {
+                    let array: [$ty; ARRAY_LEN] = ub_checks::any();
+                    let idx = any_element_index();
+                    let src = array.as_ptr().wrapping_add(idx);
+                    let value = unsafe { read_via_copy(src) };
+                    assert_eq!(value, array[idx]);
+                }
+
+                /// The element the pointer points to is the written value, and the other
+                /// elements are unchanged.
+                #[safety::proof_for_contract(write_via_move)]
+                fn check_write_via_move() {
+                    let mut array: [$ty; ARRAY_LEN] = ub_checks::any();
+                    let mut expected = array;
+                    let (idx, value) = (any_element_index(), ub_checks::any());
+                    let dst = array.as_mut_ptr().wrapping_add(idx);
+                    unsafe { write_via_move(dst, value) };
+                    expected[idx] = value;
+                    assert_eq!(array, expected);
+                }
+
+                /// Only the contract is checked, since the result may point anywhere.
+                #[safety::proof_for_contract(arith_offset)]
+                fn check_arith_offset() {
+                    let array: [$ty; ARRAY_LEN] = ub_checks::any();
+                    let ptr = array.as_ptr().wrapping_add(any_index());
+                    let _ = unsafe { arith_offset(ptr, ub_checks::any()) };
+                }
+
+                $($extra)*
+            }
+        };
+    }
+
+    // The contracts of the offset intrinsics exclude zero-sized types.
+    generate_memory_harnesses!(check_unit, (), {});
+    generate_memory_harnesses!(check_u8, u8);
+    generate_memory_harnesses!(check_u64, u64);
+    generate_memory_harnesses!(check_u16_array, [u16; 3]);
+
+    /// The sign of the result is the ordering of the byte slices.
+    #[safety::proof_for_contract(compare_bytes)]
+    fn check_compare_bytes() {
+        let left: [u8; ARRAY_LEN] = ub_checks::any();
+        let right: [u8; ARRAY_LEN] = ub_checks::any();
+        let (left_idx, right_idx, bytes) = (any_index(), any_index(), ub_checks::any());
+        let left_ptr = left.as_ptr().wrapping_add(left_idx);
+        let right_ptr = right.as_ptr().wrapping_add(right_idx);
+        let result = unsafe { compare_bytes(left_ptr, right_ptr, bytes) };
+        let expected = left[left_idx..][..bytes].cmp(&right[right_idx..][..bytes]);
+        assert_eq!(result.signum(), expected as i32);
+    }
+
+    /// Returns the vtable of `value` when it is used as a `dyn Debug`.
+    fn debug_vtable<T: fmt::Debug>(value: &T) -> *const () {
+        let metadata = ptr::metadata(value as &dyn fmt::Debug);
+        // SAFETY: `DynMetadata` is a pointer to the vtable.
+        unsafe { transmute::<ptr::DynMetadata<dyn fmt::Debug>, *const ()>(metadata) }
+    }
+
+    /// The result is the size of the type of the vtable.
+    #[safety::proof_for_contract(vtable_size)]
+    fn check_vtable_size() {
+        let value: [u16; 3] = ub_checks::any();
+        assert_eq!(unsafe { vtable_size(debug_vtable(&value)) }, size_of::<[u16; 3]>());
+        assert_eq!(unsafe { vtable_size(debug_vtable(&())) }, 0);
+    }
+
+    /// The result is the alignment of the type of the vtable.
+    #[safety::proof_for_contract(vtable_align)]
+    fn check_vtable_align() {
+        let value: u64 = ub_checks::any();
+        assert_eq!(unsafe { vtable_align(debug_vtable(&value)) }, align_of::<u64>());
+        assert_eq!(unsafe { vtable_align(debug_vtable(&0u8)) }, 1);
+    }
 }
@@ -334,3 +334,40 @@ impl<'a> BorrowedCursor<'a> {
         self.buf.filled += buf.len();
     }
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+    use crate::ub_checks;
+
+    /// Capacity of the buffers of the harnesses.
+    const BUF_LEN: usize = 8;
+
+    // pub fn reborrow<'this>(&'this mut self) -> BorrowedCursor<'this>
+    #[safety::harness]
+    fn check_reborrow() {
+        let init = ub_checks::any_in(0..=BUF_LEN);
+        let filled = ub_checks::any_in(0..=init);
+        let mut storage: [MaybeUninit<u8>; BUF_LEN] = crate::array::from_fn(|idx| {
+            if idx < init { MaybeUninit::new(ub_checks::any()) } else { MaybeUninit::uninit() }
+        });
+        let mut buf = BorrowedBuf::from(&mut storage[..]);
+        // SAFETY: the first `init` bytes were initialized above.
+        unsafe { buf.set_init(init) };
+        buf.unfilled().advance(filled);
+
+        let mut cursor = buf.unfilled();
+        let written = ub_checks::any_in(0..=cursor.capacity());
+        cursor.append(&[0; BUF_LEN][..written]);
+        let (capacity, init_len) = (cursor.capacity(), cursor.init_ref().len());
+        let buf_ptr = ptr::from_ref(&*cursor.buf);
+
+        // The transmute only shortens the lifetime, so the reference still points to the buffer.
+        let reborrowed = cursor.reborrow();
+        assert_eq!(ptr::from_ref(&*reborrowed.buf), buf_ptr);
+        assert_eq!(reborrowed.capacity(), capacity);
+        assert_eq!(reborrowed.written(), written);
+        assert_eq!(reborrowed.init_ref().len(), init_len);
+    }
+}
@@ -199,6 +199,7 @@
 #![feature(cfg_sanitize)]
 #![feature(cfg_target_has_atomic)]
 #![feature(cfg_target_has_atomic_equal_alignment)]
+#![feature(cfg_target_thread_local)]
 #![feature(cfg_ub_checks)]
 #![feature(const_for)]
 #![feature(const_mut_refs)]
@@ -232,6 +233,7 @@
 #![feature(no_sanitize)]
 #![feature(optimize_attribute)]
 #![feature(prelude_import)]
+#![feature(proc_macro_hygiene)]
 #![feature(repr_simd)]
 #![feature(rustc_allow_const_fn_unstable)]
 #![feature(rustc_attrs)]
@@ -240,6 +242,7 @@
 #![feature(staged_api)]
 #![feature(stmt_expr_attributes)]
 #![feature(target_feature_11)]
+#![feature(thread_local)]
 #![feature(trait_alias)]
 #![feature(transparent_unions)]
 #![feature(try_blocks)]
@@ -1,5 +1,13 @@
 use crate::ops::{Deref, DerefMut, DerefPure};
-use crate::ptr;
+use crate::{ptr, ub_checks};
+
+/// The locations of the `ManuallyDrop`s whose value was taken or dropped, and must not be used
+/// again.
+///
+/// The flag of a location is never unset: assigning a new value with `*slot = value` does not make
+/// the `ManuallyDrop` available again. Zero-sized `ManuallyDrop`s are never flagged, since their
+/// value can be taken any number of times.
+static CONSUMED: ub_checks::GhostFlag = ub_checks::GhostFlag::new();
 
 /// A wrapper to inhibit the compiler from automatically calling `T`’s destructor.
 /// This wrapper is 0-cost.
@@ -111,7 +119,11 @@ impl<T> ManuallyDrop<T> {
     #[must_use = "if you don't need the value, you can use `ManuallyDrop::drop` instead"]
     #[stable(feature = "manually_drop_take", since = "1.42.0")]
     #[inline]
+    #[safety::requires(CONSUMED.is_unset(slot))]
+    #[safety::ensures(|_| CONSUMED.is_set(slot) || size_of::<T>() == 0)]
     pub unsafe fn take(slot: &mut ManuallyDrop<T>) -> T {
+        #[safety::ghost]
+        CONSUMED.set(slot);
         // SAFETY: we are reading from a reference, which is guaranteed
         // to be valid for reads.
         unsafe { ptr::read(&slot.value) }
@@ -143,7 +155,11 @@ impl<T: ?Sized> ManuallyDrop<T> {
     /// [pinned]: crate::pin
     #[stable(feature = "manually_drop", since = "1.20.0")]
     #[inline]
+    #[safety::requires(CONSUMED.is_unset(slot))]
+    #[safety::ensures(|_| CONSUMED.is_set(slot) || size_of_val(slot) == 0)]
     pub unsafe fn drop(slot: &mut ManuallyDrop<T>) {
+        #[safety::ghost]
+        CONSUMED.set(slot);
         // SAFETY: we are dropping the value pointed to by a mutable reference
         // which is guaranteed to be valid for writes.
         // It is up to the caller to make sure that `slot` isn't dropped again.
@@ -170,3 +186,122 @@ impl<T: ?Sized> DerefMut for ManuallyDrop<T> {
 
 #[unstable(feature = "deref_pure_trait", issue = "87121")]
 unsafe impl<T: ?Sized> DerefPure for ManuallyDrop<T> {}
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+    use crate::cell::Cell;
+
+    /// Counts how many times it is dropped.
+    struct DropCounter<'a>(&'a Cell<usize>);
+
+    impl Drop for DropCounter<'_> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    /// Kani only checks the precondition of the function under verification, so the
+    /// precondition of `take` is asserted before calling it.
+    unsafe fn take_checked<T>(slot: &mut ManuallyDrop<T>) -> T {
+        assert!(CONSUMED.is_unset(slot));
+        unsafe { ManuallyDrop::take(slot) }
+    }
+
+    /// Same as `take_checked`, for `drop`.
+    unsafe fn drop_checked<T: ?Sized>(slot: &mut ManuallyDrop<T>) {
+        assert!(CONSUMED.is_unset(slot));
+        unsafe { ManuallyDrop::drop(slot) }
+    }
+
+    macro_rules! generate_manually_drop_harnesses {
+        ($module:ident, $ty:ty) => {
+            mod $module {
+                use super::*;
+
+                // pub const fn new(value: T) -> ManuallyDrop<T>
+                #[safety::harness]
+                fn check_new() {
+                    let value: $ty = ub_checks::any();
+                    assert_eq!(*ManuallyDrop::new(value), value);
+                }
+
+                // pub const fn into_inner(slot: ManuallyDrop<T>) -> T
+                #[safety::harness]
+                fn check_into_inner() {
+                    let value: $ty = ub_checks::any();
+                    assert_eq!(ManuallyDrop::into_inner(ManuallyDrop::new(value)), value);
+                }
+
+                // pub unsafe fn take(slot: &mut ManuallyDrop<T>) -> T
+                #[safety::proof_for_contract(ManuallyDrop::<$ty>::take)]
+                fn check_take() {
+                    let value: $ty = ub_checks::any();
+                    let mut slot = ManuallyDrop::new(value);
+                    assert_eq!(unsafe { ManuallyDrop::take(&mut slot) }, value);
+                }
+
+                /// Taking the value twice violates the precondition of the second `take`.
+                #[safety::harness]
+                #[safety::should_panic]
+                fn check_take_twice() {
+                    let mut slot = ManuallyDrop::new(ub_checks::any::<$ty>());
+                    let _ = unsafe { take_checked(&mut slot) };
+                    let _ = unsafe { take_checked(&mut slot) };
+                }
+            }
+        };
+    }
+
+    generate_manually_drop_harnesses!(check_u8, u8);
+    generate_manually_drop_harnesses!(check_char, char);
+    generate_manually_drop_harnesses!(check_u64_array, [u64; 2]);
+
+    /// A zero-sized value can be taken any number of times.
+    #[safety::harness]
+    fn check_take_unit_twice() {
+        let mut slot = ManuallyDrop::new(());
+        unsafe { take_checked(&mut slot) };
+        unsafe { take_checked(&mut slot) };
+    }
+
+    // pub unsafe fn drop(slot: &mut ManuallyDrop<T>)
+    #[safety::proof_for_contract(ManuallyDrop::drop)]
+    fn check_drop() {
+        let count = Cell::new(0);
+        let mut slot = ManuallyDrop::new(DropCounter(&count));
+        unsafe { ManuallyDrop::drop(&mut slot) };
+        assert_eq!(count.get(), 1);
+    }
+
+    #[safety::proof_for_contract(ManuallyDrop::drop)]
+    fn check_drop_slice() {
+        let count = Cell::new(0);
+        let mut slot: ManuallyDrop<[DropCounter<'_>; 3]> =
+            ManuallyDrop::new(crate::array::from_fn(|_| DropCounter(&count)));
+        let slice: &mut ManuallyDrop<[DropCounter<'_>]> = &mut slot;
+        unsafe { ManuallyDrop::drop(slice) };
+        assert_eq!(count.get(), 3);
+    }
+
+    /// Dropping the value twice violates the precondition of the second `drop`.
+    #[safety::harness]
+    #[safety::should_panic]
+    fn check_drop_twice() {
+        let count = Cell::new(0);
+        let mut slot = ManuallyDrop::new(DropCounter(&count));
+        unsafe { drop_checked(&mut slot) };
+        unsafe { drop_checked(&mut slot) };
+    }
+
+    /// Dropping the value after taking it violates the precondition of `drop`.
+    #[safety::harness]
+    #[safety::should_panic]
+    fn check_take_then_drop() {
+        let count = Cell::new(0);
+        let mut slot = ManuallyDrop::new(DropCounter(&count));
+        let _value = unsafe { take_checked(&mut slot) };
+        unsafe { drop_checked(&mut slot) };
+    }
+}
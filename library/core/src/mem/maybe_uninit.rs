@@ -1,6 +1,8 @@
+use safety::{ensures, requires};
+
 use crate::any::type_name;
 use crate::mem::{self, ManuallyDrop};
-use crate::{fmt, intrinsics, ptr, slice};
+use crate::{fmt, intrinsics, ptr, slice, ub_checks};
 
 /// A wrapper type to construct uninitialized instances of `T`.
 ///
@@ -486,6 +488,7 @@ impl<T> MaybeUninit<T> {
     #[stable(feature = "maybe_uninit_write", since = "1.55.0")]
     #[rustc_const_unstable(feature = "const_maybe_uninit_write", issue = "63567")]
     #[inline(always)]
+    #[ensures(|result| ub_checks::is_initialized(&**result, 1))]
     pub const fn write(&mut self, val: T) -> &mut T {
         *self = MaybeUninit::new(val);
         // SAFETY: We just initialized this value.
@@ -624,6 +627,7 @@ impl<T> MaybeUninit<T> {
     #[inline(always)]
     #[rustc_diagnostic_item = "assume_init"]
     #[track_caller]
+    #[requires(ub_checks::is_initialized(self.as_ptr(), 1))]
     pub const unsafe fn assume_init(self) -> T {
         // SAFETY: the caller must guarantee that `self` is initialized.
         // This also means that `self` must be a `value` variant.
@@ -694,6 +698,7 @@ impl<T> MaybeUninit<T> {
     #[rustc_const_stable(feature = "const_maybe_uninit_assume_init_read", since = "1.75.0")]
     #[inline(always)]
     #[track_caller]
+    #[requires(ub_checks::is_initialized(self.as_ptr(), 1))]
     pub const unsafe fn assume_init_read(&self) -> T {
         // SAFETY: the caller must guarantee that `self` is initialized.
         // Reading from `self.as_ptr()` is safe since `self` should be initialized.
@@ -726,6 +731,7 @@ impl<T> MaybeUninit<T> {
     /// [`assume_init`]: MaybeUninit::assume_init
     /// [`Vec<T>`]: ../../std/vec/struct.Vec.html
     #[stable(feature = "maybe_uninit_extra", since = "1.60.0")]
+    #[requires(ub_checks::is_initialized(self.as_ptr(), 1))]
     pub unsafe fn assume_init_drop(&mut self) {
         // SAFETY: the caller must guarantee that `self` is initialized and
         // satisfies all invariants of `T`.
@@ -792,6 +798,7 @@ impl<T> MaybeUninit<T> {
     #[stable(feature = "maybe_uninit_ref", since = "1.55.0")]
     #[rustc_const_stable(feature = "const_maybe_uninit_assume_init_ref", since = "1.59.0")]
     #[inline(always)]
+    #[requires(ub_checks::is_initialized(self.as_ptr(), 1))]
     pub const unsafe fn assume_init_ref(&self) -> &T {
         // SAFETY: the caller must guarantee that `self` is initialized.
         // This also means that `self` must be a `value` variant.
@@ -909,6 +916,7 @@ impl<T> MaybeUninit<T> {
     #[stable(feature = "maybe_uninit_ref", since = "1.55.0")]
     #[rustc_const_unstable(feature = "const_maybe_uninit_assume_init", issue = "none")]
     #[inline(always)]
+    #[requires(ub_checks::is_initialized(self.as_ptr(), 1))]
     pub const unsafe fn assume_init_mut(&mut self) -> &mut T {
         // SAFETY: the caller must guarantee that `self` is initialized.
         // This also means that `self` must be a `value` variant.
@@ -947,6 +955,7 @@ impl<T> MaybeUninit<T> {
     #[rustc_const_unstable(feature = "const_maybe_uninit_array_assume_init", issue = "96097")]
     #[inline(always)]
     #[track_caller]
+    #[requires(ub_checks::is_valid_value(ptr::from_ref(&array).cast::<[T; N]>()))]
     pub const unsafe fn array_assume_init<const N: usize>(array: [Self; N]) -> [T; N] {
         // SAFETY:
         // * The caller guarantees that all elements of the array are initialized
@@ -973,6 +982,7 @@ impl<T> MaybeUninit<T> {
     #[unstable(feature = "maybe_uninit_slice", issue = "63569")]
     #[rustc_const_unstable(feature = "maybe_uninit_slice", issue = "63569")]
     #[inline(always)]
+    #[requires(ub_checks::is_initialized(slice.as_ptr().cast::<T>(), slice.len()))]
     pub const unsafe fn slice_assume_init_ref(slice: &[Self]) -> &[T] {
         // SAFETY: casting `slice` to a `*const [T]` is safe since the caller guarantees that
         // `slice` is initialized, and `MaybeUninit` is guaranteed to have the same layout as `T`.
@@ -995,6 +1005,7 @@ impl<T> MaybeUninit<T> {
     #[unstable(feature = "maybe_uninit_slice", issue = "63569")]
     #[rustc_const_unstable(feature = "const_maybe_uninit_assume_init", issue = "none")]
     #[inline(always)]
+    #[requires(ub_checks::is_initialized(slice.as_ptr().cast::<T>(), slice.len()))]
     pub const unsafe fn slice_assume_init_mut(slice: &mut [Self]) -> &mut [T] {
         // SAFETY: similar to safety notes for `slice_get_ref`, but we have a
         // mutable reference which is also guaranteed to be valid for writes.
@@ -1490,3 +1501,180 @@ impl<T: Copy> SpecFill<T> for [MaybeUninit<T>] {
         self.fill(MaybeUninit::new(value));
     }
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+
+    /// Length of the arrays of the harnesses.
+    const ARRAY_LEN: usize = 4;
+
+    /// Returns a value that is either initialized or not.
+    fn any_maybe_init<T: ub_checks::Arbitrary>() -> MaybeUninit<T> {
+        if ub_checks::any() { MaybeUninit::new(ub_checks::any()) } else { MaybeUninit::uninit() }
+    }
+
+    /// Returns an array whose elements are each either initialized or not.
+    fn any_partially_init<T: ub_checks::Arbitrary>() -> [MaybeUninit<T>; ARRAY_LEN] {
+        crate::array::from_fn(|_| any_maybe_init())
+    }
+
+    macro_rules! generate_transmute_harnesses {
+        ($module:ident, $ty:ty) => {
+            mod $module {
+                use super::*;
+
+                // pub const unsafe fn array_assume_init<const N: usize>(array: [Self; N]) -> [T; N]
+                #[safety::proof_for_contract(MaybeUninit::<$ty>::array_assume_init)]
+                fn check_array_assume_init() {
+                    let array = any_partially_init::<$ty>();
+                    let _ = unsafe { MaybeUninit::array_assume_init(array) };
+                    let elems = ptr::from_ref(&array).cast::<[$ty; ARRAY_LEN]>();
+                    // The contract requires every element to be initialized.
+                    assert!(ub_checks::is_valid_value(elems));
+                }
+
+                // pub const fn transpose(self) -> [MaybeUninit<T>; N]
+                #[safety::harness]
+                fn check_transpose_uninit_array() {
+                    let init: bool = ub_checks::any();
+                    let array: [$ty; ARRAY_LEN] = ub_checks::any();
+                    let value = if init { MaybeUninit::new(array) } else { MaybeUninit::uninit() };
+                    let result = value.transpose();
+                    if init {
+                        let elems = ptr::from_ref(&result).cast::<[$ty; ARRAY_LEN]>();
+                        // The elements were initialized before transposing.
+                        assert!(ub_checks::is_valid_value(elems));
+                        assert_eq!(unsafe { elems.read() }, array);
+                    }
+                }
+
+                // pub const fn transpose(self) -> MaybeUninit<[T; N]>
+                #[safety::harness]
+                fn check_transpose_array_of_uninit() {
+                    let inits: [bool; ARRAY_LEN] = ub_checks::any();
+                    let values: [$ty; ARRAY_LEN] = ub_checks::any();
+                    let array: [MaybeUninit<$ty>; ARRAY_LEN] = crate::array::from_fn(|idx| {
+                        if inits[idx] {
+                            MaybeUninit::new(values[idx])
+                        } else {
+                            MaybeUninit::uninit()
+                        }
+                    });
+                    let result = array.transpose();
+                    let elems = result.as_ptr().cast::<$ty>();
+                    for idx in (0..ARRAY_LEN).filter(|idx| inits[*idx]) {
+                        // The element was initialized before transposing.
+                        assert!(ub_checks::is_valid_value(elems.wrapping_add(idx)));
+                        assert_eq!(unsafe { elems.add(idx).read() }, values[idx]);
+                    }
+                }
+            }
+        };
+    }
+
+    generate_transmute_harnesses!(check_unit, ());
+    generate_transmute_harnesses!(check_bool, bool);
+    generate_transmute_harnesses!(check_char, char);
+    generate_transmute_harnesses!(check_u32_array, [u32; 2]);
+
+    /// Generates harnesses for the functions that require their argument to be initialized, which
+    /// only succeed if the arbitrary buffers they get happen to be fully initialized.
+    macro_rules! generate_init_harnesses {
+        ($module:ident, $ty:ty) => {
+            mod $module {
+                use super::*;
+
+                // pub const fn write(&mut self, val: T) -> &mut T
+                #[safety::proof_for_contract(MaybeUninit::<$ty>::write)]
+                fn check_write() {
+                    let mut value = any_maybe_init::<$ty>();
+                    let val: $ty = ub_checks::any();
+                    assert_eq!(*value.write(val), val);
+                }
+
+                // pub const unsafe fn assume_init(self) -> T
+                #[safety::proof_for_contract(MaybeUninit::<$ty>::assume_init)]
+                fn check_assume_init() {
+                    let value = any_maybe_init::<$ty>();
+                    let _ = unsafe { value.assume_init() };
+                }
+
+                // pub const unsafe fn assume_init_read(&self) -> T
+                #[safety::proof_for_contract(MaybeUninit::<$ty>::assume_init_read)]
+                fn check_assume_init_read() {
+                    let value = any_maybe_init::<$ty>();
+                    let _ = unsafe { value.assume_init_read() };
+                }
+
+                // pub unsafe fn assume_init_drop(&mut self)
+                #[safety::proof_for_contract(MaybeUninit::<$ty>::assume_init_drop)]
+                fn check_assume_init_drop() {
+                    let mut value = any_maybe_init::<$ty>();
+                    unsafe { value.assume_init_drop() };
+                }
+
+                // pub const unsafe fn assume_init_ref(&self) -> &T
+                #[safety::proof_for_contract(MaybeUninit::<$ty>::assume_init_ref)]
+                fn check_assume_init_ref() {
+                    let value = any_maybe_init::<$ty>();
+                    let _ = unsafe { value.assume_init_ref() };
+                }
+
+                // pub const unsafe fn assume_init_mut(&mut self) -> &mut T
+                #[safety::proof_for_contract(MaybeUninit::<$ty>::assume_init_mut)]
+                fn check_assume_init_mut() {
+                    let mut value = any_maybe_init::<$ty>();
+                    let _ = unsafe { value.assume_init_mut() };
+                }
+
+                // pub const unsafe fn slice_assume_init_ref(slice: &[Self]) -> &[T]
+                #[safety::proof_for_contract(MaybeUninit::<$ty>::slice_assume_init_ref)]
+                fn check_slice_assume_init_ref() {
+                    let array = any_partially_init::<$ty>();
+                    let len = ub_checks::any_in(0..=ARRAY_LEN);
+                    let result = unsafe { MaybeUninit::slice_assume_init_ref(&array[..len]) };
+                    assert_eq!(result.len(), len);
+                }
+
+                // pub const unsafe fn slice_assume_init_mut(slice: &mut [Self]) -> &mut [T]
+                #[safety::proof_for_contract(MaybeUninit::<$ty>::slice_assume_init_mut)]
+                fn check_slice_assume_init_mut() {
+                    let mut array = any_partially_init::<$ty>();
+                    let len = ub_checks::any_in(0..=ARRAY_LEN);
+                    let result = unsafe { MaybeUninit::slice_assume_init_mut(&mut array[..len]) };
+                    assert_eq!(result.len(), len);
+                }
+
+                // pub fn copy_from_slice<'a>(this: &'a mut [MaybeUninit<T>], src: &[T])
+                //     -> &'a mut [T]
+                #[safety::harness]
+                fn check_copy_from_slice() {
+                    let mut array = any_partially_init::<$ty>();
+                    let src: [$ty; ARRAY_LEN] = ub_checks::any();
+                    let len = ub_checks::any_in(0..=ARRAY_LEN);
+                    let result = MaybeUninit::copy_from_slice(&mut array[..len], &src[..len]);
+                    assert!(ub_checks::is_initialized(result.as_ptr(), result.len()));
+                    assert_eq!(result, &src[..len]);
+                }
+            }
+        };
+    }
+
+    generate_init_harnesses!(check_init_unit, ());
+    generate_init_harnesses!(check_init_u8, u8);
+    generate_init_harnesses!(check_init_char, char);
+    generate_init_harnesses!(check_init_u64_array, [u64; 3]);
+
+    // pub const unsafe fn assume_init(self) -> T
+    /// Only the first element of the buffer is written, so reading the array must be reported as
+    /// a read of uninitialized memory.
+    #[safety::harness]
+    #[safety::should_panic]
+    fn check_assume_init_partly_written() {
+        let mut buffer = MaybeUninit::<[u8; 2]>::uninit();
+        unsafe { buffer.as_mut_ptr().cast::<u8>().write(ub_checks::any()) };
+        let _ = unsafe { buffer.assume_init() };
+    }
+}
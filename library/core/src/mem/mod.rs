@@ -7,7 +7,8 @@
 
 use crate::alloc::Layout;
 use crate::marker::DiscriminantKind;
-use crate::{clone, cmp, fmt, hash, intrinsics, ptr};
+use crate::ub_checks::Snapshot;
+use crate::{clone, cmp, fmt, hash, intrinsics, ptr, ub_checks};
 
 #[cfg(kani)]
 use crate::kani;
@@ -394,6 +395,8 @@ pub const fn size_of_val<T: ?Sized>(val: &T) -> usize {
 #[must_use]
 #[unstable(feature = "layout_for_ptr", issue = "69835")]
 #[rustc_const_unstable(feature = "const_size_of_val_raw", issue = "46571")]
+#[safety::requires(ub_checks::has_valid_metadata(val))]
+#[safety::ensures(|result| *result <= isize::MAX as usize)]
 pub const unsafe fn size_of_val_raw<T: ?Sized>(val: *const T) -> usize {
     // SAFETY: the caller must provide a valid raw pointer
     unsafe { intrinsics::size_of_val(val) }
@@ -538,6 +541,8 @@ pub const fn align_of_val<T: ?Sized>(val: &T) -> usize {
 #[must_use]
 #[unstable(feature = "layout_for_ptr", issue = "69835")]
 #[rustc_const_unstable(feature = "const_align_of_val_raw", issue = "46571")]
+#[safety::requires(ub_checks::has_valid_metadata(val))]
+#[safety::ensures(|result| result.is_power_of_two())]
 pub const unsafe fn align_of_val_raw<T: ?Sized>(val: *const T) -> usize {
     // SAFETY: the caller must provide a valid raw pointer
     unsafe { intrinsics::min_align_of_val(val) }
@@ -655,6 +660,7 @@ pub const fn needs_drop<T: ?Sized>() -> bool {
 #[rustc_diagnostic_item = "mem_zeroed"]
 #[track_caller]
 #[rustc_const_stable(feature = "const_mem_zeroed", since = "1.75.0")]
+#[safety::requires(ub_checks::is_zero_valid::<T>())]
 pub const unsafe fn zeroed<T>() -> T {
     // SAFETY: the caller must guarantee that an all-zero value is valid for `T`.
     unsafe {
@@ -694,6 +700,9 @@ pub const unsafe fn zeroed<T>() -> T {
 #[allow(deprecated)]
 #[rustc_diagnostic_item = "mem_uninitialized"]
 #[track_caller]
+// The bytes are filled with `0x01` at runtime, but they must be considered uninitialized, which
+// is only valid for types such as `MaybeUninit`.
+#[safety::requires(ub_checks::is_uninit_valid::<T>())]
 pub unsafe fn uninitialized<T>() -> T {
     // SAFETY: the caller must guarantee that an uninitialized value is valid for `T`.
     unsafe {
@@ -732,8 +741,8 @@ pub unsafe fn uninitialized<T>() -> T {
 #[stable(feature = "rust1", since = "1.0.0")]
 #[rustc_const_unstable(feature = "const_swap", issue = "83163")]
 #[rustc_diagnostic_item = "mem_swap"]
-#[cfg_attr(kani, crate::kani::modifies(x))]
-#[cfg_attr(kani, crate::kani::modifies(y))]
+#[safety::modifies(x)]
+#[safety::modifies(y)]
 pub const fn swap<T>(x: &mut T, y: &mut T) {
     // SAFETY: `&mut` guarantees these are typed readable and writable
     // as well as non-overlapping.
@@ -797,6 +806,9 @@ pub const fn swap<T>(x: &mut T, y: &mut T) {
 /// ```
 #[inline]
 #[stable(feature = "mem_take", since = "1.40.0")]
+#[safety::modifies(dest)]
+#[safety::ensures(|result| ub_checks::is_same_value(result, old(Snapshot::of(&*dest)).get()))]
+#[safety::ensures(|_| ub_checks::is_same_value(&*dest, &T::default()))]
 pub fn take<T: Default>(dest: &mut T) -> T {
     replace(dest, T::default())
 }
@@ -864,6 +876,9 @@ pub fn take<T: Default>(dest: &mut T) -> T {
 #[must_use = "if you don't need the old value, you can just assign the new value directly"]
 #[rustc_const_unstable(feature = "const_replace", issue = "83164")]
 #[cfg_attr(not(test), rustc_diagnostic_item = "mem_replace")]
+#[safety::modifies(dest)]
+#[safety::ensures(|result| ub_checks::is_same_value(result, old(Snapshot::of(&*dest)).get()))]
+#[safety::ensures(|_| ub_checks::is_same_value(&*dest, old(Snapshot::of(&src)).get()))]
 pub const fn replace<T>(dest: &mut T, src: T) -> T {
     // It may be tempting to use `swap` to avoid `unsafe` here. Don't!
     // The compiler optimizes the implementation below to two `memcpy`s
@@ -1013,6 +1028,11 @@ pub const fn copy<T: Copy>(x: &T) -> T {
 #[track_caller]
 #[stable(feature = "rust1", since = "1.0.0")]
 #[rustc_const_stable(feature = "const_transmute_copy", since = "1.74.0")]
+// A `Dst` larger than `Src` makes the function panic. `src` does not need to be aligned for `Dst`.
+#[safety::requires(
+    size_of::<Src>() < size_of::<Dst>()
+        || ub_checks::is_valid_value(ptr::from_ref(src).cast::<Dst>())
+)]
 pub const unsafe fn transmute_copy<Src, Dst>(src: &Src) -> Dst {
     assert!(
         size_of::<Src>() >= size_of::<Dst>(),
@@ -1381,29 +1401,198 @@ mod verify {
 
     /// Use this type to ensure that mem swap does not drop the value.
     #[derive(kani::Arbitrary)]
-    struct CannotDrop<T: kani::Arbitrary> {
+    struct CannotDrop<T: ub_checks::Arbitrary> {
         inner: T,
     }
 
-    impl<T: kani::Arbitrary> Drop for CannotDrop<T> {
+    impl<T: ub_checks::Arbitrary> Drop for CannotDrop<T> {
         fn drop(&mut self) {
             unreachable!("Cannot drop")
         }
     }
 
-    #[kani::proof_for_contract(swap)]
+    #[safety::proof_for_contract(swap)]
     pub fn check_swap_primitive() {
-        let mut x: u8 = kani::any();
-        let mut y: u8 = kani::any();
+        let mut x: u8 = ub_checks::any();
+        let mut y: u8 = ub_checks::any();
         swap(&mut x, &mut y)
     }
 
-    #[kani::proof_for_contract(swap)]
+    #[safety::proof_for_contract(swap)]
     pub fn check_swap_adt_no_drop() {
-        let mut x: CannotDrop<char> = kani::any();
-        let mut y: CannotDrop<char> = kani::any();
+        let mut x: CannotDrop<char> = ub_checks::any();
+        let mut y: CannotDrop<char> = ub_checks::any();
         swap(&mut x, &mut y);
         forget(x);
         forget(y);
     }
+
+    // Use the verified contract of `typed_swap` instead of its body.
+    #[safety::proof_for_contract(swap)]
+    #[safety::stub_verified(intrinsics::typed_swap)]
+    pub fn check_swap_stub_typed_swap() {
+        let mut x: u32 = ub_checks::any();
+        let mut y: u32 = ub_checks::any();
+        swap(&mut x, &mut y)
+    }
+
+    // pub const fn replace<T>(dest: &mut T, src: T) -> T
+    #[safety::proof_for_contract(replace)]
+    pub fn check_replace_primitive() {
+        let mut dest: char = ub_checks::any();
+        let src: char = ub_checks::any();
+        let old = dest;
+        assert_eq!(replace(&mut dest, src), old);
+        assert_eq!(dest, src);
+    }
+
+    #[safety::proof_for_contract(replace)]
+    pub fn check_replace_adt_no_drop() {
+        let mut dest: CannotDrop<u16> = ub_checks::any();
+        let src: CannotDrop<u16> = ub_checks::any();
+        let (old_inner, src_inner) = (dest.inner, src.inner);
+        let old = replace(&mut dest, src);
+        assert_eq!((old.inner, dest.inner), (old_inner, src_inner));
+        forget(old);
+        forget(dest);
+    }
+
+    #[safety::proof_for_contract(replace)]
+    pub fn check_replace_enum() {
+        let mut dest: Option<char> = ub_checks::any();
+        let src: Option<char> = ub_checks::any();
+        let old = dest;
+        assert_eq!(replace(&mut dest, src), old);
+        assert_eq!(dest, src);
+    }
+
+    // pub fn take<T: Default>(dest: &mut T) -> T
+    #[safety::proof_for_contract(take)]
+    pub fn check_take() {
+        let mut dest: [u32; 2] = ub_checks::any();
+        let old = dest;
+        assert_eq!(take(&mut dest), old);
+        assert_eq!(dest, [0; 2]);
+    }
+
+    #[safety::proof_for_contract(take)]
+    pub fn check_take_enum() {
+        let mut dest: Option<u8> = ub_checks::any();
+        let old = dest;
+        assert_eq!(take(&mut dest), old);
+        assert_eq!(dest, None);
+    }
+
+    // pub const fn forget<T>(t: T)
+    #[safety::harness]
+    pub fn check_forget() {
+        let value: CannotDrop<u64> = ub_checks::any();
+        forget(value);
+    }
+
+    /// Generates a harness for `zeroed::<$ty>`, where `$zero` matches the value whose bytes are
+    /// all zero.
+    macro_rules! generate_zeroed_harness {
+        ($module:ident, $ty:ty, $zero:pat) => {
+            mod $module {
+                use super::*;
+
+                // pub const unsafe fn zeroed<T>() -> T
+                #[safety::proof_for_contract(zeroed::<$ty>)]
+                pub fn check_zeroed() {
+                    let value: $ty = unsafe { zeroed() };
+                    assert!(matches!(value, $zero));
+                }
+            }
+        };
+    }
+
+    generate_zeroed_harness!(check_u128, u128, 0);
+    generate_zeroed_harness!(check_option_ref, Option<&u8>, None);
+    generate_zeroed_harness!(check_option_fn, Option<fn()>, None);
+    generate_zeroed_harness!(check_option_nonzero, Option<crate::num::NonZero<u32>>, None);
+    generate_zeroed_harness!(check_tuple, (bool, char, f32), (false, '\0', 0.0));
+
+    /// Generates a harness where `zeroed::<$ty>` must fail, since zero is not a valid `$ty`.
+    macro_rules! generate_invalid_zeroed_harness {
+        ($module:ident, $ty:ty) => {
+            mod $module {
+                use super::*;
+
+                // pub const unsafe fn zeroed<T>() -> T
+                #[safety::harness]
+                #[safety::should_panic]
+                pub fn check_zeroed() {
+                    let _: $ty = unsafe { zeroed() };
+                }
+            }
+        };
+    }
+
+    generate_invalid_zeroed_harness!(check_nonzero_u8, crate::num::NonZero<u8>);
+    generate_invalid_zeroed_harness!(check_ref, &u8);
+    generate_invalid_zeroed_harness!(check_fn, fn());
+
+    // pub unsafe fn uninitialized<T>() -> T
+    #[allow(deprecated)]
+    #[safety::proof_for_contract(uninitialized::<MaybeUninit<u32>>)]
+    pub fn check_uninitialized() {
+        let _: MaybeUninit<u32> = unsafe { uninitialized() };
+    }
+
+    macro_rules! generate_transmute_copy_harness {
+        ($module:ident, $src:ty, $dst:ty) => {
+            mod $module {
+                use super::*;
+
+                // pub const unsafe fn transmute_copy<Src, Dst>(src: &Src) -> Dst
+                #[safety::proof_for_contract(transmute_copy::<$src, $dst>)]
+                pub fn check_transmute_copy() {
+                    let src: $src = ub_checks::any();
+                    let _: $dst = unsafe { transmute_copy(&src) };
+                    let bytes = ptr::from_ref(&src).cast::<$dst>();
+                    // `Src` is at least as large as `Dst` and initialized.
+                    assert!(ub_checks::is_valid_value(bytes));
+                }
+            }
+        };
+    }
+
+    // `Dst` is more aligned than `Src`, so it is read unaligned.
+    generate_transmute_copy_harness!(check_u8_array_to_u64, [u8; 8], u64);
+    generate_transmute_copy_harness!(check_u64_to_u16, u64, u16);
+    generate_transmute_copy_harness!(check_u32_to_char, u32, char);
+    generate_transmute_copy_harness!(check_u8_array_to_bool, [u8; 2], bool);
+
+    macro_rules! generate_layout_of_val_raw_harnesses {
+        ($module:ident, $ty:ty, |$array:ident| $value:expr) => {
+            mod $module {
+                use super::*;
+
+                // pub const unsafe fn size_of_val_raw<T: ?Sized>(val: *const T) -> usize
+                #[safety::proof_for_contract(size_of_val_raw::<$ty>)]
+                pub fn check_size_of_val_raw() {
+                    let $array: [u16; 4] = ub_checks::any();
+                    let value: &$ty = $value;
+                    assert_eq!(unsafe { size_of_val_raw(value) }, size_of_val(value));
+                }
+
+                // pub const unsafe fn align_of_val_raw<T: ?Sized>(val: *const T) -> usize
+                #[safety::proof_for_contract(align_of_val_raw::<$ty>)]
+                pub fn check_align_of_val_raw() {
+                    let $array: [u16; 4] = ub_checks::any();
+                    let value: &$ty = $value;
+                    assert_eq!(unsafe { align_of_val_raw(value) }, align_of_val(value));
+                }
+            }
+        };
+    }
+
+    generate_layout_of_val_raw_harnesses!(check_sized, [u16; 4], |array| &array);
+    generate_layout_of_val_raw_harnesses!(check_slice, [u16], |array| {
+        &array[ub_checks::any_in(0..=4usize)..]
+    });
+    generate_layout_of_val_raw_harnesses!(check_dyn, dyn fmt::Debug, |array| {
+        &array[ub_checks::any_in(0..4usize)]
+    });
 }
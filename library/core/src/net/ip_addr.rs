@@ -2348,3 +2348,21 @@ bitop_impls! {
     #[stable(feature = "ip_bitops", since = "1.75.0")]
     impl (BitOr, BitOrAssign) for Ipv6Addr = (bitor, bitor_assign);
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+    use crate::ub_checks;
+
+    // pub const fn segments(&self) -> [u16; 8]
+    #[safety::harness]
+    fn check_segments() {
+        let octets: [u8; 16] = ub_checks::any();
+        // Every bit pattern is a valid `u16`, so only the values of the segments are checked.
+        let segments = Ipv6Addr::from(octets).segments();
+        for (segment, pair) in iter::zip(segments, octets.chunks_exact(2)) {
+            assert_eq!(segment, u16::from_be_bytes([pair[0], pair[1]]));
+        }
+    }
+}
@@ -19,6 +19,11 @@
 )]
 #![macro_use]
 
+use crate::ub_checks::Invariant;
+
+#[cfg(kani)]
+use crate::kani;
+
 /// Arithmetic operations required by bignums.
 pub trait FullOps: Sized {
     /// Returns `(carry', v')` such that `carry' * 2^W + v' = self * other + other2 + carry`,
@@ -78,6 +83,9 @@ macro_rules! define_bignum {
         ///
         /// All operations available to bignums panic in the case of overflows.
         /// The caller is responsible to use large enough bignum types.
+        #[safety::invariant(
+            self.base.get(self.size..).is_some_and(|unused| unused.iter().all(|&v| v == 0))
+        )]
         pub struct $name {
             /// One plus the offset to the maximum "digit" in use.
             /// This does not decrease, so be aware of the computation order.
@@ -115,6 +123,26 @@ macro_rules! define_bignum {
                 &self.base[..self.size]
             }
 
+            /// Returns the value of `digits`, least significant first as in [`Self::digits`], or
+            /// `None` if it does not fit in a `u128`.
+            ///
+            /// This is only used by contracts, which can thus only relate values below `2^128`.
+            fn value_of(digits: &[$ty]) -> Option<u128> {
+                digits.iter().rev().try_fold(0u128, |value, &digit| {
+                    value.checked_mul(1 << <$ty>::BITS)?.checked_add(digit as u128)
+                })
+            }
+
+            /// Returns the value of the bignum, or `None` if it does not fit in a `u128`.
+            fn value(&self) -> Option<u128> {
+                Self::value_of(self.digits())
+            }
+
+            /// Returns whether the bignum is valid and equal to `value`, if the latter is known.
+            fn has_value(&self, value: Option<u128>) -> bool {
+                self.is_safe() && value.is_none_or(|value| self.value() == Some(value))
+            }
+
             /// Returns the `i`-th bit where bit 0 is the least significant one.
             /// In other words, the bit with weight `2^i`.
             pub fn get_bit(&self, i: usize) -> u8 {
@@ -144,6 +172,9 @@ macro_rules! define_bignum {
             }
 
             /// Adds `other` to itself and returns its own mutable reference.
+            #[safety::ensures(|result| result.has_value(
+                old(self.value()).zip(other.value()).and_then(|(a, b)| a.checked_add(b))
+            ))]
             pub fn add<'a>(&'a mut self, other: &$name) -> &'a mut $name {
                 use crate::{cmp, iter};
 
@@ -179,6 +210,9 @@ macro_rules! define_bignum {
             }
 
             /// Subtracts `other` from itself and returns its own mutable reference.
+            #[safety::ensures(|result| result.has_value(
+                old(self.value()).zip(other.value()).and_then(|(a, b)| a.checked_sub(b))
+            ))]
             pub fn sub<'a>(&'a mut self, other: &$name) -> &'a mut $name {
                 use crate::{cmp, iter};
 
@@ -213,6 +247,11 @@ macro_rules! define_bignum {
             }
 
             /// Multiplies itself by `2^bits` and returns its own mutable reference.
+            #[safety::ensures(|result| result.has_value(
+                old(self.value())
+                    .zip(u32::try_from(bits).ok())
+                    .and_then(|(a, bits)| a.checked_mul(2u128.checked_pow(bits)?))
+            ))]
             pub fn mul_pow2(&mut self, bits: usize) -> &mut $name {
                 let digitbits = <$ty>::BITS as usize;
                 let digits = bits / digitbits;
@@ -252,6 +291,11 @@ macro_rules! define_bignum {
             }
 
             /// Multiplies itself by `5^e` and returns its own mutable reference.
+            #[safety::ensures(|result| result.has_value(
+                old(self.value())
+                    .zip(u32::try_from(old(e)).ok())
+                    .and_then(|(a, e)| a.checked_mul(5u128.checked_pow(e)?))
+            ))]
             pub fn mul_pow5(&mut self, mut e: usize) -> &mut $name {
                 use crate::mem;
                 use crate::num::bignum::SMALL_POW5;
@@ -281,6 +325,9 @@ macro_rules! define_bignum {
             /// Multiplies itself by a number described by `other[0] + other[1] * 2^W +
             /// other[2] * 2^(2W) + ...` (where `W` is the number of bits in the digit type)
             /// and returns its own mutable reference.
+            #[safety::ensures(|result| result.has_value(
+                old(self.value()).zip(Self::value_of(other)).and_then(|(a, b)| a.checked_mul(b))
+            ))]
             pub fn mul_digits<'a>(&'a mut self, other: &[$ty]) -> &'a mut $name {
                 // the internal routine. works best when aa.len() <= bb.len().
                 fn mul_inner(ret: &mut [$ty; $n], aa: &[$ty], bb: &[$ty]) -> usize {
@@ -339,6 +386,13 @@ macro_rules! define_bignum {
 
             /// Divide self by another bignum, overwriting `q` with the quotient and `r` with the
             /// remainder.
+            #[safety::ensures(|_| {
+                let (quotient, remainder) = match self.value().zip(d.value()) {
+                    Some((a, b)) => (Some(a / b), Some(a % b)),
+                    None => (None, None),
+                };
+                q.has_value(quotient) && r.has_value(remainder)
+            })]
             pub fn div_rem(&self, d: &$name, q: &mut $name, r: &mut $name) {
                 // Stupid slow base-2 long division taken from
                 // https://en.wikipedia.org/wiki/Division_algorithm
@@ -428,5 +482,78 @@ define_bignum!(Big32x40: type=Digit32, n=40);
 // this one is used for testing only.
 #[doc(hidden)]
 pub mod tests {
+    use crate::ub_checks::Invariant;
+
+    #[cfg(kani)]
+    use crate::kani;
+
     define_bignum!(Big8x3: type=u8, n=3);
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+    use crate::ub_checks;
+
+    /// Generates a bignum with at most `max_size` digits in use, where `max_size <= 4`, so that
+    /// contracts can relate its value to the result of an operation.
+    fn any_big(max_size: usize) -> Big32x40 {
+        let digits: [Digit32; 4] = ub_checks::any();
+        let size = ub_checks::any_in(0..=max_size);
+        let mut base = [0; 40];
+        base[..size].copy_from_slice(&digits[..size]);
+        Big32x40 { size, base }
+    }
+
+    // pub fn add<'a>(&'a mut self, other: &Big32x40) -> &'a mut Big32x40
+    #[safety::proof_for_contract(Big32x40::add)]
+    fn check_add() {
+        let _ = any_big(3).add(&any_big(3));
+    }
+
+    // pub fn sub<'a>(&'a mut self, other: &Big32x40) -> &'a mut Big32x40
+    #[safety::proof_for_contract(Big32x40::sub)]
+    fn check_sub() {
+        let (mut x, y) = (any_big(4), any_big(4));
+        // Subtracting a larger value panics.
+        if x >= y {
+            let _ = x.sub(&y);
+        }
+    }
+
+    // pub fn mul_pow2(&mut self, bits: usize) -> &mut Big32x40
+    #[safety::proof_for_contract(Big32x40::mul_pow2)]
+    fn check_mul_pow2() {
+        let bits: usize = ub_checks::any_in(0..64);
+        let _ = any_big(2).mul_pow2(bits);
+    }
+
+    // pub fn mul_pow5(&mut self, mut e: usize) -> &mut Big32x40
+    #[safety::proof_for_contract(Big32x40::mul_pow5)]
+    fn check_mul_pow5() {
+        // `5^27 < 2^63`, and this multiplies by the largest digit-sized power twice.
+        let e: usize = ub_checks::any_in(0..=27);
+        let _ = any_big(2).mul_pow5(e);
+    }
+
+    // pub fn mul_digits<'a>(&'a mut self, other: &[Digit32]) -> &'a mut Big32x40
+    #[safety::proof_for_contract(Big32x40::mul_digits)]
+    fn check_mul_digits() {
+        let other: [Digit32; 2] = ub_checks::any();
+        let len: usize = ub_checks::any_in(0..=2);
+        let _ = any_big(2).mul_digits(&other[..len]);
+    }
+
+    // pub fn div_rem(&self, d: &Big32x40, q: &mut Big32x40, r: &mut Big32x40)
+    #[safety::proof_for_contract(Big32x40::div_rem)]
+    fn check_div_rem() {
+        let (x, d) = (any_big(2), any_big(2));
+        // The quotient and the remainder are overwritten, whatever their previous value.
+        let (mut q, mut r) = (any_big(4), any_big(4));
+        // Dividing by zero panics.
+        if !d.is_zero() {
+            x.div_rem(&d, &mut q, &mut r);
+        }
+    }
+}
@@ -9,6 +9,8 @@
     issue = "none"
 )]
 
+use safety::{ensures, requires};
+
 /// A custom 64-bit floating point type, representing `f * 2^e`.
 #[derive(Copy, Clone, Debug)]
 #[doc(hidden)]
@@ -21,6 +23,9 @@ pub struct Fp {
 
 impl Fp {
     /// Returns a correctly rounded product of itself and `other`.
+    #[requires(self.e.checked_add(other.e).and_then(|e| e.checked_add(64)).is_some())]
+    #[ensures(|result| result.f as u128 == (self.f as u128 * other.f as u128 + (1 << 63)) >> 64
+        && result.e == self.e + other.e + 64)]
     pub fn mul(&self, other: &Fp) -> Fp {
         const MASK: u64 = 0xffffffff;
         let a = self.f >> 32;
@@ -38,6 +43,10 @@ impl Fp {
     }
 
     /// Normalizes itself so that the resulting mantissa is at least `2^63`.
+    #[requires(self.f != 0 && self.e.checked_sub(self.f.leading_zeros() as i16).is_some())]
+    #[ensures(|result| result.f >= 1 << 63
+        && result.f == self.f << self.f.leading_zeros()
+        && result.e == self.e - self.f.leading_zeros() as i16)]
     pub fn normalize(&self) -> Fp {
         let mut f = self.f;
         let mut e = self.e;
@@ -79,3 +88,26 @@ impl Fp {
         Fp { f: self.f << edelta, e }
     }
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+    use crate::ub_checks;
+
+    fn any_fp() -> Fp {
+        Fp { f: ub_checks::any(), e: ub_checks::any() }
+    }
+
+    // pub fn mul(&self, other: &Fp) -> Fp
+    #[safety::proof_for_contract(Fp::mul)]
+    fn check_mul() {
+        let _ = any_fp().mul(&any_fp());
+    }
+
+    // pub fn normalize(&self) -> Fp
+    #[safety::proof_for_contract(Fp::normalize)]
+    fn check_normalize() {
+        let _ = any_fp().normalize();
+    }
+}
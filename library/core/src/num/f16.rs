@@ -11,11 +11,14 @@
 
 #![unstable(feature = "f16", issue = "116909")]
 
+use safety::requires;
+
 use crate::convert::FloatToInt;
 #[cfg(not(test))]
 use crate::intrinsics;
 use crate::mem;
 use crate::num::FpCategory;
+use crate::ub_checks;
 
 /// Basic mathematical constants.
 #[unstable(feature = "f16", issue = "116909")]
@@ -895,6 +898,7 @@ impl f16 {
     #[inline]
     #[unstable(feature = "f16", issue = "116909")]
     #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[requires(ub_checks::float_fits_in::<Int>(self))]
     pub unsafe fn to_int_unchecked<Int>(self) -> Int
     where
         Self: FloatToInt<Int>,
@@ -11,11 +11,14 @@
 
 #![stable(feature = "rust1", since = "1.0.0")]
 
+use safety::requires;
+
 use crate::convert::FloatToInt;
 #[cfg(not(test))]
 use crate::intrinsics;
 use crate::mem;
 use crate::num::FpCategory;
+use crate::ub_checks;
 
 /// The radix or base of the internal representation of `f32`.
 /// Use [`f32::RADIX`] instead.
@@ -1086,6 +1089,7 @@ impl f32 {
                   without modifying the original"]
     #[stable(feature = "float_approx_unchecked_to", since = "1.44.0")]
     #[inline]
+    #[requires(ub_checks::float_fits_in::<Int>(self))]
     pub unsafe fn to_int_unchecked<Int>(self) -> Int
     where
         Self: FloatToInt<Int>,
@@ -1,8 +1,21 @@
 /// These functions compute the integer logarithm of their type, assuming
 /// that someone has already checked that the value is strictly positive.
 
+use safety::{ensures, requires};
+
+/// Whether `$log` is the base-10 logarithm of `$val` of type `$T`: `10^log <= val < 10^(log + 1)`,
+/// where `10^(log + 1)` may not fit in `$T`.
+macro_rules! is_ilog10 {
+    ($T:ident, $val:expr, $log:expr) => {
+        $T::checked_pow(10, $log).is_some_and(|low| low <= $val)
+            && $T::checked_pow(10, $log + 1).is_none_or(|high| $val < high)
+    };
+}
+
 // 0 < val <= u8::MAX
 #[inline]
+#[requires(val > 0)]
+#[ensures(|log| is_ilog10!(u8, val, *log))]
 pub const fn u8(val: u8) -> u32 {
     let val = val as u32;
 
@@ -24,6 +37,8 @@ pub const fn u8(val: u8) -> u32 {
 
 // 0 < val < 100_000
 #[inline]
+#[requires(val > 0 && val < 100_000)]
+#[ensures(|log| is_ilog10!(u32, val, *log))]
 const fn less_than_5(val: u32) -> u32 {
     // Similar to u8, when adding one of these constants to val,
     // we get two possible bit patterns above the low 17 bits,
@@ -45,12 +60,16 @@ const fn less_than_5(val: u32) -> u32 {
 
 // 0 < val <= u16::MAX
 #[inline]
+#[requires(val > 0)]
+#[ensures(|log| is_ilog10!(u16, val, *log))]
 pub const fn u16(val: u16) -> u32 {
     less_than_5(val as u32)
 }
 
 // 0 < val <= u32::MAX
 #[inline]
+#[requires(val > 0)]
+#[ensures(|log| { let val = old(val); is_ilog10!(u32, val, *log) })]
 pub const fn u32(mut val: u32) -> u32 {
     let mut log = 0;
     if val >= 100_000 {
@@ -62,6 +81,8 @@ pub const fn u32(mut val: u32) -> u32 {
 
 // 0 < val <= u64::MAX
 #[inline]
+#[requires(val > 0)]
+#[ensures(|log| { let val = old(val); is_ilog10!(u64, val, *log) })]
 pub const fn u64(mut val: u64) -> u32 {
     let mut log = 0;
     if val >= 10_000_000_000 {
@@ -77,6 +98,8 @@ pub const fn u64(mut val: u64) -> u32 {
 
 // 0 < val <= u128::MAX
 #[inline]
+#[requires(val > 0)]
+#[ensures(|log| { let val = old(val); is_ilog10!(u128, val, *log) })]
 pub const fn u128(mut val: u128) -> u32 {
     let mut log = 0;
     if val >= 100_000_000_000_000_000_000_000_000_000_000 {
@@ -93,48 +116,64 @@ pub const fn u128(mut val: u128) -> u32 {
 
 #[cfg(target_pointer_width = "16")]
 #[inline]
+#[requires(val > 0)]
+#[ensures(|log| is_ilog10!(usize, val, *log))]
 pub const fn usize(val: usize) -> u32 {
     u16(val as _)
 }
 
 #[cfg(target_pointer_width = "32")]
 #[inline]
+#[requires(val > 0)]
+#[ensures(|log| is_ilog10!(usize, val, *log))]
 pub const fn usize(val: usize) -> u32 {
     u32(val as _)
 }
 
 #[cfg(target_pointer_width = "64")]
 #[inline]
+#[requires(val > 0)]
+#[ensures(|log| is_ilog10!(usize, val, *log))]
 pub const fn usize(val: usize) -> u32 {
     u64(val as _)
 }
 
 // 0 < val <= i8::MAX
 #[inline]
+#[requires(val > 0)]
+#[ensures(|log| is_ilog10!(i8, val, *log))]
 pub const fn i8(val: i8) -> u32 {
     u8(val as u8)
 }
 
 // 0 < val <= i16::MAX
 #[inline]
+#[requires(val > 0)]
+#[ensures(|log| is_ilog10!(i16, val, *log))]
 pub const fn i16(val: i16) -> u32 {
     u16(val as u16)
 }
 
 // 0 < val <= i32::MAX
 #[inline]
+#[requires(val > 0)]
+#[ensures(|log| is_ilog10!(i32, val, *log))]
 pub const fn i32(val: i32) -> u32 {
     u32(val as u32)
 }
 
 // 0 < val <= i64::MAX
 #[inline]
+#[requires(val > 0)]
+#[ensures(|log| is_ilog10!(i64, val, *log))]
 pub const fn i64(val: i64) -> u32 {
     u64(val as u64)
 }
 
 // 0 < val <= i128::MAX
 #[inline]
+#[requires(val > 0)]
+#[ensures(|log| is_ilog10!(i128, val, *log))]
 pub const fn i128(val: i128) -> u32 {
     u128(val as u128)
 }
@@ -146,3 +185,95 @@ pub const fn i128(val: i128) -> u32 {
 pub const fn panic_for_nonpositive_argument() -> ! {
     panic!("argument of integer logarithm must be positive")
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+    use crate::ub_checks;
+
+    /// Narrow inputs are checked against the definition in `u128`, where `10^(log + 1)` fits.
+    #[safety::harness]
+    fn check_ilog10_narrow() {
+        let val = ub_checks::any_in(1..=u8::MAX);
+        assert!(is_ilog10!(u128, val as u128, u8(val)));
+        let val = ub_checks::any_in(1..=u16::MAX);
+        assert!(is_ilog10!(u128, val as u128, u16(val)));
+        let val = ub_checks::any_in(1..=i8::MAX);
+        assert!(is_ilog10!(u128, val as u128, i8(val)));
+        let val = ub_checks::any_in(1..=i16::MAX);
+        assert!(is_ilog10!(u128, val as u128, i16(val)));
+    }
+
+    // const fn less_than_5(val: u32) -> u32
+    #[safety::proof_for_contract(less_than_5)]
+    fn check_less_than_5() {
+        let _ = less_than_5(ub_checks::any());
+    }
+
+    // pub const fn u8(val: u8) -> u32
+    #[safety::proof_for_contract(super::u8)]
+    fn check_u8() {
+        let _ = u8(ub_checks::any());
+    }
+
+    // pub const fn u16(val: u16) -> u32
+    #[safety::proof_for_contract(super::u16)]
+    fn check_u16() {
+        let _ = u16(ub_checks::any());
+    }
+
+    // pub const fn u32(mut val: u32) -> u32
+    #[safety::proof_for_contract(super::u32)]
+    fn check_u32() {
+        let _ = u32(ub_checks::any());
+    }
+
+    // pub const fn u64(mut val: u64) -> u32
+    #[safety::proof_for_contract(super::u64)]
+    fn check_u64() {
+        let _ = u64(ub_checks::any());
+    }
+
+    // pub const fn u128(mut val: u128) -> u32
+    #[safety::proof_for_contract(super::u128)]
+    fn check_u128() {
+        let _ = u128(ub_checks::any());
+    }
+
+    // pub const fn usize(val: usize) -> u32
+    #[safety::proof_for_contract(super::usize)]
+    fn check_usize() {
+        let _ = usize(ub_checks::any());
+    }
+
+    // pub const fn i8(val: i8) -> u32
+    #[safety::proof_for_contract(super::i8)]
+    fn check_i8() {
+        let _ = i8(ub_checks::any());
+    }
+
+    // pub const fn i16(val: i16) -> u32
+    #[safety::proof_for_contract(super::i16)]
+    fn check_i16() {
+        let _ = i16(ub_checks::any());
+    }
+
+    // pub const fn i32(val: i32) -> u32
+    #[safety::proof_for_contract(super::i32)]
+    fn check_i32() {
+        let _ = i32(ub_checks::any());
+    }
+
+    // pub const fn i64(val: i64) -> u32
+    #[safety::proof_for_contract(super::i64)]
+    fn check_i64() {
+        let _ = i64(ub_checks::any());
+    }
+
+    // pub const fn i128(val: i128) -> u32
+    #[safety::proof_for_contract(super::i128)]
+    fn check_i128() {
+        let _ = i128(ub_checks::any());
+    }
+}
@@ -511,16 +511,8 @@ macro_rules! int_impl {
                       without modifying the original"]
         #[inline(always)]
         #[cfg_attr(miri, track_caller)] // even without panics, this helps for Miri backtraces
+        #[requires(!self.overflowing_add(rhs).1)]
         pub const unsafe fn unchecked_add(self, rhs: Self) -> Self {
-            assert_unsafe_precondition!(
-                check_language_ub,
-                concat!(stringify!($SelfT), "::unchecked_add cannot overflow"),
-                (
-                    lhs: $SelfT = self,
-                    rhs: $SelfT = rhs,
-                ) => !lhs.overflowing_add(rhs).1,
-            );
-
             // SAFETY: this is guaranteed to be safe by the caller.
             unsafe {
                 intrinsics::unchecked_add(self, rhs)
@@ -663,16 +655,8 @@ macro_rules! int_impl {
                       without modifying the original"]
         #[inline(always)]
         #[cfg_attr(miri, track_caller)] // even without panics, this helps for Miri backtraces
+        #[requires(!self.overflowing_sub(rhs).1)]
         pub const unsafe fn unchecked_sub(self, rhs: Self) -> Self {
-            assert_unsafe_precondition!(
-                check_language_ub,
-                concat!(stringify!($SelfT), "::unchecked_sub cannot overflow"),
-                (
-                    lhs: $SelfT = self,
-                    rhs: $SelfT = rhs,
-                ) => !lhs.overflowing_sub(rhs).1,
-            );
-
             // SAFETY: this is guaranteed to be safe by the caller.
             unsafe {
                 intrinsics::unchecked_sub(self, rhs)
@@ -815,16 +799,8 @@ macro_rules! int_impl {
                       without modifying the original"]
         #[inline(always)]
         #[cfg_attr(miri, track_caller)] // even without panics, this helps for Miri backtraces
+        #[requires(!self.overflowing_mul(rhs).1)]
         pub const unsafe fn unchecked_mul(self, rhs: Self) -> Self {
-            assert_unsafe_precondition!(
-                check_language_ub,
-                concat!(stringify!($SelfT), "::unchecked_mul cannot overflow"),
-                (
-                    lhs: $SelfT = self,
-                    rhs: $SelfT = rhs,
-                ) => !lhs.overflowing_mul(rhs).1,
-            );
-
             // SAFETY: this is guaranteed to be safe by the caller.
             unsafe {
                 intrinsics::unchecked_mul(self, rhs)
@@ -1164,15 +1140,8 @@ macro_rules! int_impl {
         #[rustc_const_unstable(feature = "unchecked_neg", issue = "85122")]
         #[inline(always)]
         #[cfg_attr(miri, track_caller)] // even without panics, this helps for Miri backtraces
+        #[requires(!self.overflowing_neg().1)]
         pub const unsafe fn unchecked_neg(self) -> Self {
-            assert_unsafe_precondition!(
-                check_language_ub,
-                concat!(stringify!($SelfT), "::unchecked_neg cannot overflow"),
-                (
-                    lhs: $SelfT = self,
-                ) => !lhs.overflowing_neg().1,
-            );
-
             // SAFETY: this is guaranteed to be safe by the caller.
             unsafe {
                 intrinsics::unchecked_sub(0, self)
@@ -1297,15 +1266,8 @@ macro_rules! int_impl {
         #[rustc_const_unstable(feature = "unchecked_shifts", issue = "85122")]
         #[inline(always)]
         #[cfg_attr(miri, track_caller)] // even without panics, this helps for Miri backtraces
+        #[requires(rhs < <$ActualT>::BITS)]
         pub const unsafe fn unchecked_shl(self, rhs: u32) -> Self {
-            assert_unsafe_precondition!(
-                check_language_ub,
-                concat!(stringify!($SelfT), "::unchecked_shl cannot overflow"),
-                (
-                    rhs: u32 = rhs,
-                ) => rhs < <$ActualT>::BITS,
-            );
-
             // SAFETY: this is guaranteed to be safe by the caller.
             unsafe {
                 intrinsics::unchecked_shl(self, rhs)
@@ -1423,15 +1385,8 @@ macro_rules! int_impl {
         #[rustc_const_unstable(feature = "unchecked_shifts", issue = "85122")]
         #[inline(always)]
         #[cfg_attr(miri, track_caller)] // even without panics, this helps for Miri backtraces
+        #[requires(rhs < <$ActualT>::BITS)]
         pub const unsafe fn unchecked_shr(self, rhs: u32) -> Self {
-            assert_unsafe_precondition!(
-                check_language_ub,
-                concat!(stringify!($SelfT), "::unchecked_shr cannot overflow"),
-                (
-                    rhs: u32 = rhs,
-                ) => rhs < <$ActualT>::BITS,
-            );
-
             // SAFETY: this is guaranteed to be safe by the caller.
             unsafe {
                 intrinsics::unchecked_shr(self, rhs)
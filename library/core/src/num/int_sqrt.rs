@@ -9,6 +9,8 @@
 //! "Paul Zimmermann. Karatsuba Square Root. \[Research Report\] RR-3805,
 //! INRIA. 1999, pp.8. (inria-00072854)"
 
+use safety::{ensures, requires};
+
 /// This array stores the [integer square roots](
 /// https://en.wikipedia.org/wiki/Integer_square_root) and remainders of each
 /// [`u8`](prim@u8) value. For example, `U8_ISQRT_WITH_REMAINDER[17]` will be
@@ -37,6 +39,8 @@ const U8_ISQRT_WITH_REMAINDER: [(u8, u8); 256] = {
 #[must_use = "this returns the result of the operation, \
               without modifying the original"]
 #[inline]
+#[ensures(|result| result.checked_mul(*result).is_some_and(|square| square <= n)
+    && (*result + 1).checked_mul(*result + 1).is_none_or(|square| n < square))]
 pub const fn u8(n: u8) -> u8 {
     U8_ISQRT_WITH_REMAINDER[n as usize].0
 }
@@ -58,6 +62,9 @@ macro_rules! signed_fn {
         #[must_use = "this returns the result of the operation, \
                       without modifying the original"]
         #[inline]
+        #[requires(n >= 0)]
+        #[ensures(|result| result.checked_mul(*result).is_some_and(|square| square <= n)
+            && (*result + 1).checked_mul(*result + 1).is_none_or(|square| n < square))]
         pub const unsafe fn $SignedT(n: $SignedT) -> $SignedT {
             debug_assert!(n >= 0, "Negative input inside `isqrt`.");
             $UnsignedT(n as $UnsignedT) as $SignedT
@@ -83,6 +90,8 @@ macro_rules! unsigned_fn {
         #[must_use = "this returns the result of the operation, \
                       without modifying the original"]
         #[inline]
+        #[ensures(|result| result.checked_mul(*result).is_some_and(|square| square <= old(n))
+            && (*result + 1).checked_mul(*result + 1).is_none_or(|square| old(n) < square))]
         pub const fn $UnsignedT(mut n: $UnsignedT) -> $UnsignedT {
             if n <= <$HalfBitsT>::MAX as $UnsignedT {
                 $HalfBitsT(n as $HalfBitsT) as $UnsignedT
@@ -314,3 +323,88 @@ unsigned_fn!(u128, u64, u128_stages);
 pub const fn panic_for_negative_argument() -> ! {
     panic!("argument of integer square root cannot be negative")
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+    use crate::ub_checks;
+
+    /// Returns whether `root` is the integer square root of `n`: `root² <= n < (root + 1)²`.
+    fn is_isqrt(n: u128, root: u128) -> bool {
+        root * root <= n && n < (root + 1) * (root + 1)
+    }
+
+    /// Narrow inputs are checked against the definition in `u128`, where `(root + 1)²` fits.
+    #[safety::harness]
+    fn check_isqrt_narrow() {
+        let n: u8 = ub_checks::any();
+        assert!(is_isqrt(n as u128, u8(n) as u128));
+        let n: u16 = ub_checks::any();
+        assert!(is_isqrt(n as u128, u16(n) as u128));
+        let n = ub_checks::any_in(0..=i8::MAX);
+        assert!(is_isqrt(n as u128, unsafe { i8(n) } as u128));
+        let n = ub_checks::any_in(0..=i16::MAX);
+        assert!(is_isqrt(n as u128, unsafe { i16(n) } as u128));
+    }
+
+    // pub const fn u8(n: u8) -> u8
+    #[safety::proof_for_contract(super::u8)]
+    fn check_u8() {
+        let _ = u8(ub_checks::any());
+    }
+
+    // pub const fn u16(mut n: u16) -> u16
+    #[safety::proof_for_contract(super::u16)]
+    fn check_u16() {
+        let _ = u16(ub_checks::any());
+    }
+
+    // pub const fn u32(mut n: u32) -> u32
+    #[safety::proof_for_contract(super::u32)]
+    fn check_u32() {
+        let _ = u32(ub_checks::any());
+    }
+
+    // pub const fn u64(mut n: u64) -> u64
+    #[safety::proof_for_contract(super::u64)]
+    fn check_u64() {
+        let _ = u64(ub_checks::any());
+    }
+
+    // pub const fn u128(mut n: u128) -> u128
+    #[safety::proof_for_contract(super::u128)]
+    fn check_u128() {
+        let _ = u128(ub_checks::any());
+    }
+
+    // pub const unsafe fn i8(n: i8) -> i8
+    #[safety::proof_for_contract(super::i8)]
+    fn check_i8() {
+        let _ = unsafe { i8(ub_checks::any()) };
+    }
+
+    // pub const unsafe fn i16(n: i16) -> i16
+    #[safety::proof_for_contract(super::i16)]
+    fn check_i16() {
+        let _ = unsafe { i16(ub_checks::any()) };
+    }
+
+    // pub const unsafe fn i32(n: i32) -> i32
+    #[safety::proof_for_contract(super::i32)]
+    fn check_i32() {
+        let _ = unsafe { i32(ub_checks::any()) };
+    }
+
+    // pub const unsafe fn i64(n: i64) -> i64
+    #[safety::proof_for_contract(super::i64)]
+    fn check_i64() {
+        let _ = unsafe { i64(ub_checks::any()) };
+    }
+
+    // pub const unsafe fn i128(n: i128) -> i128
+    #[safety::proof_for_contract(super::i128)]
+    fn check_i128() {
+        let _ = unsafe { i128(ub_checks::any()) };
+    }
+}
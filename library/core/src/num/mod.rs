@@ -2,8 +2,9 @@
 
 #![stable(feature = "rust1", since = "1.0.0")]
 
+use safety::requires;
+
 use crate::str::FromStr;
-use crate::ub_checks::assert_unsafe_precondition;
 use crate::{ascii, intrinsics, mem};
 
 // Used because the `?` operator is not allowed in a const context.
@@ -1581,3 +1582,116 @@ from_str_radix_size_impl! { i16 isize, u16 usize }
 from_str_radix_size_impl! { i32 isize, u32 usize }
 #[cfg(target_pointer_width = "64")]
 from_str_radix_size_impl! { i64 isize, u64 usize }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+    use crate::ub_checks;
+
+    macro_rules! generate_int_harnesses {
+        ($module:ident, $ty:ty, signed) => {
+            generate_int_harnesses!($module, $ty, {
+                // pub const unsafe fn unchecked_neg(self) -> Self
+                #[safety::proof_for_contract(<$ty>::unchecked_neg)]
+                fn check_unchecked_neg() {
+                    let x: $ty = ub_checks::any();
+                    assert_eq!(unsafe { x.unchecked_neg() }, x.wrapping_neg());
+                }
+            });
+        };
+        ($module:ident, $ty:ty, unsigned) => {
+            generate_int_harnesses!($module, $ty, {
+                // pub const fn widening_mul(self, rhs: Self) -> (Self, Self)
+                #[safety::harness]
+                fn check_widening_mul() {
+                    let (x, y): ($ty, $ty) = (ub_checks::any(), ub_checks::any());
+                    let (low, high) = x.widening_mul(y);
+                    assert_eq!(low, x.wrapping_mul(y));
+                    assert_eq!(high == 0, x.checked_mul(y).is_some());
+                }
+
+                // pub const fn carrying_mul(self, rhs: Self, carry: Self) -> (Self, Self)
+                #[safety::harness]
+                fn check_carrying_mul() {
+                    let (x, y, carry): ($ty, $ty, $ty) =
+                        (ub_checks::any(), ub_checks::any(), ub_checks::any());
+                    let (low, high) = x.widening_mul(y);
+                    let (low, overflow) = low.overflowing_add(carry);
+                    // The high half is at most `MAX - 1`, so adding the carry cannot overflow.
+                    assert_eq!(x.carrying_mul(y, carry), (low, high + overflow as $ty));
+                }
+            });
+        };
+        ($module:ident, $ty:ty, { $($extra:item)* }) => {
+            mod $module {
+                use super::*;
+
+                // pub const unsafe fn unchecked_add(self, rhs: Self) -> Self
+                #[safety::proof_for_contract(<$ty>::unchecked_add)]
+                fn check_unchecked_add() {
+                    let (x, y): ($ty, $ty) = (ub_checks::any(), ub_checks::any());
+                    assert_eq!(unsafe { x.unchecked_add(y) }, x.wrapping_add(y));
+                }
+
+                // pub const unsafe fn unchecked_sub(self, rhs: Self) -> Self
+                #[safety::proof_for_contract(<$ty>::unchecked_sub)]
+                fn check_unchecked_sub() {
+                    let (x, y): ($ty, $ty) = (ub_checks::any(), ub_checks::any());
+                    assert_eq!(unsafe { x.unchecked_sub(y) }, x.wrapping_sub(y));
+                }
+
+                // pub const unsafe fn unchecked_mul(self, rhs: Self) -> Self
+                #[safety::proof_for_contract(<$ty>::unchecked_mul)]
+                fn check_unchecked_mul() {
+                    let (x, y): ($ty, $ty) = (ub_checks::any(), ub_checks::any());
+                    assert_eq!(unsafe { x.unchecked_mul(y) }, x.wrapping_mul(y));
+                }
+
+                // pub const unsafe fn unchecked_shl(self, rhs: u32) -> Self
+                #[safety::proof_for_contract(<$ty>::unchecked_shl)]
+                fn check_unchecked_shl() {
+                    let (x, rhs): ($ty, u32) = (ub_checks::any(), ub_checks::any());
+                    assert_eq!(unsafe { x.unchecked_shl(rhs) }, x << rhs);
+                }
+
+                // pub const unsafe fn unchecked_shr(self, rhs: u32) -> Self
+                #[safety::proof_for_contract(<$ty>::unchecked_shr)]
+                fn check_unchecked_shr() {
+                    let (x, rhs): ($ty, u32) = (ub_checks::any(), ub_checks::any());
+                    assert_eq!(unsafe { x.unchecked_shr(rhs) }, x >> rhs);
+                }
+
+                // pub const fn wrapping_shl(self, rhs: u32) -> Self
+                #[safety::harness]
+                fn check_wrapping_shl() {
+                    let (x, rhs): ($ty, u32) = (ub_checks::any(), ub_checks::any());
+                    assert_eq!(x.wrapping_shl(rhs), x << (rhs % <$ty>::BITS));
+                }
+
+                // pub const fn wrapping_shr(self, rhs: u32) -> Self
+                #[safety::harness]
+                fn check_wrapping_shr() {
+                    let (x, rhs): ($ty, u32) = (ub_checks::any(), ub_checks::any());
+                    assert_eq!(x.wrapping_shr(rhs), x >> (rhs % <$ty>::BITS));
+                }
+
+                $($extra)*
+            }
+        };
+    }
+
+    generate_int_harnesses!(check_i8, i8, signed);
+    generate_int_harnesses!(check_i16, i16, signed);
+    generate_int_harnesses!(check_i32, i32, signed);
+    generate_int_harnesses!(check_i64, i64, signed);
+    generate_int_harnesses!(check_i128, i128, signed);
+    generate_int_harnesses!(check_isize, isize, signed);
+    generate_int_harnesses!(check_u8, u8, unsigned);
+    generate_int_harnesses!(check_u16, u16, unsigned);
+    generate_int_harnesses!(check_u32, u32, unsigned);
+    generate_int_harnesses!(check_u64, u64, unsigned);
+    // `u128` has no wider type, so it does not implement `widening_mul` and `carrying_mul`.
+    generate_int_harnesses!(check_u128, u128, {});
+    generate_int_harnesses!(check_usize, usize, unsigned);
+}
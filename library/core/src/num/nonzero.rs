@@ -1,5 +1,7 @@
 //! Definitions of integer that is known not to equal zero.
 
+use safety::{ensures, requires};
+
 use super::{IntErrorKind, ParseIntError};
 use crate::cmp::Ordering;
 use crate::hash::{Hash, Hasher};
@@ -7,8 +9,12 @@ use crate::marker::{Freeze, StructuralPartialEq};
 use crate::ops::{BitOr, BitOrAssign, Div, DivAssign, Neg, Rem, RemAssign};
 use crate::panic::{RefUnwindSafe, UnwindSafe};
 use crate::str::FromStr;
+use crate::ub_checks::Invariant;
 use crate::{fmt, intrinsics, ptr, ub_checks};
 
+#[cfg(kani)]
+use crate::kani;
+
 /// A marker trait for primitive types which can be zero.
 ///
 /// This is an implementation detail for <code>[NonZero]\<T></code> which may disappear or be replaced at any time.
@@ -107,8 +113,27 @@ impl_zeroable_primitive!(
 #[repr(transparent)]
 #[rustc_nonnull_optimization_guaranteed]
 #[rustc_diagnostic_item = "NonZero"]
+// SAFETY: `NonZero<T>` has the same layout as `T`, and primitives have no padding.
+#[safety::invariant(unsafe { has_nonzero_byte(self) })]
 pub struct NonZero<T: ZeroablePrimitive>(T::NonZeroInner);
 
+/// Returns whether any byte of `value` is non-zero.
+///
+/// Zeroable primitives are only known to be integers, so contracts check their bytes instead of
+/// comparing them to zero.
+///
+/// # Safety
+///
+/// `U` must not have padding, e.g., it is a zeroable primitive or a `NonZero` of one.
+#[inline]
+unsafe fn has_nonzero_byte<U>(value: &U) -> bool {
+    // SAFETY: the caller guarantees that every byte of `value` is initialized.
+    let bytes = unsafe {
+        crate::slice::from_raw_parts(ptr::from_ref(value).cast::<u8>(), crate::mem::size_of::<U>())
+    };
+    bytes.iter().any(|&byte| byte != 0)
+}
+
 macro_rules! impl_nonzero_fmt {
     ($Trait:ident) => {
         #[stable(feature = "nonzero", since = "1.28.0")]
@@ -229,18 +254,21 @@ where
     }
 
     #[inline]
+    #[ensures(|result| result.get() == self.get().max(other.get()))]
     fn max(self, other: Self) -> Self {
         // SAFETY: The maximum of two non-zero values is still non-zero.
         unsafe { Self::new_unchecked(self.get().max(other.get())) }
     }
 
     #[inline]
+    #[ensures(|result| result.get() == self.get().min(other.get()))]
     fn min(self, other: Self) -> Self {
         // SAFETY: The minimum of two non-zero values is still non-zero.
         unsafe { Self::new_unchecked(self.get().min(other.get())) }
     }
 
     #[inline]
+    #[ensures(|result| result.get() == self.get().clamp(min.get(), max.get()))]
     fn clamp(self, min: Self, max: Self) -> Self {
         // SAFETY: A non-zero value clamped between two non-zero values is still non-zero.
         unsafe { Self::new_unchecked(self.get().clamp(min.get(), max.get())) }
@@ -281,6 +309,7 @@ where
     type Output = Self;
 
     #[inline]
+    #[ensures(|result| result.is_safe())]
     fn bitor(self, rhs: Self) -> Self::Output {
         // SAFETY: Bitwise OR of two non-zero values is still non-zero.
         unsafe { Self::new_unchecked(self.get() | rhs.get()) }
@@ -295,6 +324,7 @@ where
     type Output = Self;
 
     #[inline]
+    #[ensures(|result| result.is_safe())]
     fn bitor(self, rhs: T) -> Self::Output {
         // SAFETY: Bitwise OR of a non-zero value with anything is still non-zero.
         unsafe { Self::new_unchecked(self.get() | rhs) }
@@ -309,6 +339,7 @@ where
     type Output = NonZero<T>;
 
     #[inline]
+    #[ensures(|result| result.is_safe())]
     fn bitor(self, rhs: NonZero<T>) -> Self::Output {
         // SAFETY: Bitwise OR of anything with a non-zero value is still non-zero.
         unsafe { NonZero::new_unchecked(self | rhs.get()) }
@@ -346,12 +377,19 @@ where
     /// Creates a non-zero if the given value is not zero.
     #[stable(feature = "nonzero", since = "1.28.0")]
     #[rustc_const_stable(feature = "const_nonzero_int_methods", since = "1.47.0")]
+    #[rustc_allow_const_fn_unstable(const_ub_checks)]
     #[must_use]
     #[inline]
+    // SAFETY: zeroable primitives have no padding.
+    #[ensures(|result| result.is_some() == unsafe { has_nonzero_byte(&n) })]
     pub const fn new(n: T) -> Option<Self> {
         // SAFETY: Memory layout optimization guarantees that `Option<NonZero<T>>` has
         //         the same layout and size as `T`, with `0` representing `None`.
-        unsafe { intrinsics::transmute_unchecked(n) }
+        let result: Option<Self> = unsafe { intrinsics::transmute_unchecked(n) };
+        if let Some(nonzero) = &result {
+            ub_checks::assert_invariant(nonzero);
+        }
+        result
     }
 
     /// Creates a non-zero without checking whether the value is non-zero.
@@ -364,6 +402,9 @@ where
     #[rustc_const_stable(feature = "nonzero", since = "1.28.0")]
     #[must_use]
     #[inline]
+    // SAFETY: zeroable primitives have no padding.
+    #[requires(unsafe { has_nonzero_byte(&n) })]
+    #[ensures(|result| result.is_safe())]
     pub const unsafe fn new_unchecked(n: T) -> Self {
         match Self::new(n) {
             Some(n) => n,
@@ -390,6 +431,9 @@ where
         // SAFETY: Memory layout optimization guarantees that `Option<NonZero<T>>` has
         //         the same layout and size as `T`, with `0` representing `None`.
         let opt_n = unsafe { &mut *(ptr::from_mut(n).cast::<Option<Self>>()) };
+        if let Some(nonzero) = &*opt_n {
+            ub_checks::assert_invariant(nonzero);
+        }
 
         opt_n.as_mut()
     }
@@ -631,6 +675,7 @@ macro_rules! nonzero_integer {
             #[must_use = "this returns the result of the operation, \
                         without modifying the original"]
             #[inline(always)]
+            #[ensures(|result| result.get() == self.get().rotate_left(n))]
             pub const fn rotate_left(self, n: u32) -> Self {
                 let result = self.get().rotate_left(n);
                 // SAFETY: Rotating bits preserves the property int > 0.
@@ -664,6 +709,7 @@ macro_rules! nonzero_integer {
             #[must_use = "this returns the result of the operation, \
                         without modifying the original"]
             #[inline(always)]
+            #[ensures(|result| result.get() == self.get().rotate_right(n))]
             pub const fn rotate_right(self, n: u32) -> Self {
                 let result = self.get().rotate_right(n);
                 // SAFETY: Rotating bits preserves the property int > 0.
@@ -693,6 +739,7 @@ macro_rules! nonzero_integer {
             #[must_use = "this returns the result of the operation, \
                         without modifying the original"]
             #[inline(always)]
+            #[ensures(|result| result.get() == self.get().swap_bytes())]
             pub const fn swap_bytes(self) -> Self {
                 let result = self.get().swap_bytes();
                 // SAFETY: Shuffling bytes preserves the property int > 0.
@@ -723,6 +770,7 @@ macro_rules! nonzero_integer {
             #[must_use = "this returns the result of the operation, \
                         without modifying the original"]
             #[inline(always)]
+            #[ensures(|result| result.get() == self.get().reverse_bits())]
             pub const fn reverse_bits(self) -> Self {
                 let result = self.get().reverse_bits();
                 // SAFETY: Reversing bits preserves the property int > 0.
@@ -758,6 +806,7 @@ macro_rules! nonzero_integer {
             #[unstable(feature = "nonzero_bitwise", issue = "128281")]
             #[must_use]
             #[inline(always)]
+            #[ensures(|result| result.get() == $Int::from_be(x.get()))]
             pub const fn from_be(x: Self) -> Self {
                 let result = $Int::from_be(x.get());
                 // SAFETY: Shuffling bytes preserves the property int > 0.
@@ -793,6 +842,7 @@ macro_rules! nonzero_integer {
             #[unstable(feature = "nonzero_bitwise", issue = "128281")]
             #[must_use]
             #[inline(always)]
+            #[ensures(|result| result.get() == $Int::from_le(x.get()))]
             pub const fn from_le(x: Self) -> Self {
                 let result = $Int::from_le(x.get());
                 // SAFETY: Shuffling bytes preserves the property int > 0.
@@ -899,6 +949,7 @@ macro_rules! nonzero_integer {
             #[must_use = "this returns the result of the operation, \
                           without modifying the original"]
             #[inline]
+            #[ensures(|result| result.map(Self::get) == self.get().checked_mul(other.get()))]
             pub const fn checked_mul(self, other: Self) -> Option<Self> {
                 if let Some(result) = self.get().checked_mul(other.get()) {
                     // SAFETY:
@@ -938,6 +989,7 @@ macro_rules! nonzero_integer {
             #[must_use = "this returns the result of the operation, \
                           without modifying the original"]
             #[inline]
+            #[ensures(|result| result.get() == self.get().saturating_mul(other.get()))]
             pub const fn saturating_mul(self, other: Self) -> Self {
                 // SAFETY:
                 // - `saturating_mul` returns `u*::MAX`/`i*::MAX`/`i*::MIN` on overflow/underflow,
@@ -986,6 +1038,8 @@ macro_rules! nonzero_integer {
             #[must_use = "this returns the result of the operation, \
                           without modifying the original"]
             #[inline]
+            #[requires(!self.get().overflowing_mul(other.get()).1)]
+            #[ensures(|result| result.get() == self.get().wrapping_mul(other.get()))]
             pub const unsafe fn unchecked_mul(self, other: Self) -> Self {
                 // SAFETY: The caller ensures there is no overflow.
                 unsafe { Self::new_unchecked(self.get().unchecked_mul(other.get())) }
@@ -1016,6 +1070,7 @@ macro_rules! nonzero_integer {
             #[must_use = "this returns the result of the operation, \
                           without modifying the original"]
             #[inline]
+            #[ensures(|result| result.map(Self::get) == self.get().checked_pow(other))]
             pub const fn checked_pow(self, other: u32) -> Option<Self> {
                 if let Some(result) = self.get().checked_pow(other) {
                     // SAFETY:
@@ -1200,6 +1255,7 @@ macro_rules! nonzero_integer_signedness_dependent_impls {
             type Output = Self;
 
             #[inline]
+            #[ensures(|result| result.get() == self.get().wrapping_neg())]
             fn neg(self) -> Self {
                 // SAFETY: negation of nonzero cannot yield zero values.
                 unsafe { Self::new_unchecked(self.get().neg()) }
@@ -1271,6 +1327,7 @@ macro_rules! nonzero_integer_signedness_dependent_methods {
         #[must_use = "this returns the result of the operation, \
                       without modifying the original"]
         #[inline]
+        #[ensures(|result| result.map(Self::get) == self.get().checked_add(other))]
         pub const fn checked_add(self, other: $Int) -> Option<Self> {
             if let Some(result) = self.get().checked_add(other) {
                 // SAFETY:
@@ -1310,6 +1367,7 @@ macro_rules! nonzero_integer_signedness_dependent_methods {
         #[must_use = "this returns the result of the operation, \
                       without modifying the original"]
         #[inline]
+        #[ensures(|result| result.get() == self.get().saturating_add(other))]
         pub const fn saturating_add(self, other: $Int) -> Self {
             // SAFETY:
             // - `saturating_add` returns `u*::MAX` on overflow, which is non-zero
@@ -1348,6 +1406,8 @@ macro_rules! nonzero_integer_signedness_dependent_methods {
         #[must_use = "this returns the result of the operation, \
                       without modifying the original"]
         #[inline]
+        #[requires(!self.get().overflowing_add(other).1)]
+        #[ensures(|result| result.get() == self.get().wrapping_add(other))]
         pub const unsafe fn unchecked_add(self, other: $Int) -> Self {
             // SAFETY: The caller ensures there is no overflow.
             unsafe { Self::new_unchecked(self.get().unchecked_add(other)) }
@@ -2171,3 +2231,182 @@ nonzero_integer! {
     swapped = "0x5634129078563412",
     reversed = "0x6a2c48091e6a2c48",
 }
+
+// A zero reaching `new_unchecked` in the body of a method hits `intrinsics::unreachable`, so the
+// harnesses below also prove that the methods only create non-zero values.
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+
+    macro_rules! generate_nonzero_harnesses {
+        ($module:ident, $Int:ty, signed) => {
+            generate_nonzero_harnesses!($module, $Int, {
+                // fn neg(self) -> Self
+                #[safety::proof_for_contract(<NonZero<$Int> as Neg>::neg)]
+                fn check_neg() {
+                    // Negating `MIN` overflows, which panics in debug builds.
+                    let x = ub_checks::any_where(|x: &$Int| *x != 0 && *x != <$Int>::MIN);
+                    let _ = -NonZero::new(x).unwrap();
+                }
+            });
+        };
+        ($module:ident, $Int:ty, unsigned) => {
+            generate_nonzero_harnesses!($module, $Int, {
+                // pub const fn checked_add(self, other: $Int) -> Option<Self>
+                #[safety::proof_for_contract(NonZero::<$Int>::checked_add)]
+                fn check_checked_add() {
+                    let _ = any_nonzero().checked_add(ub_checks::any());
+                }
+
+                // pub const fn saturating_add(self, other: $Int) -> Self
+                #[safety::proof_for_contract(NonZero::<$Int>::saturating_add)]
+                fn check_saturating_add() {
+                    let _ = any_nonzero().saturating_add(ub_checks::any());
+                }
+
+                // pub const unsafe fn unchecked_add(self, other: $Int) -> Self
+                #[safety::proof_for_contract(NonZero::<$Int>::unchecked_add)]
+                fn check_unchecked_add() {
+                    let _ = unsafe { any_nonzero().unchecked_add(ub_checks::any()) };
+                }
+            });
+        };
+        ($module:ident, $Int:ty, { $($extra:item)* }) => {
+            mod $module {
+                use super::*;
+
+                fn any_nonzero() -> NonZero<$Int> {
+                    NonZero::new(ub_checks::any_where(|x: &$Int| *x != 0)).unwrap()
+                }
+
+                // pub const fn new(n: T) -> Option<Self>
+                #[safety::proof_for_contract(NonZero::<$Int>::new)]
+                fn check_new() {
+                    let _ = NonZero::<$Int>::new(ub_checks::any());
+                }
+
+                // pub const unsafe fn new_unchecked(n: T) -> Self
+                #[safety::proof_for_contract(NonZero::<$Int>::new_unchecked)]
+                fn check_new_unchecked() {
+                    let _ = unsafe { NonZero::<$Int>::new_unchecked(ub_checks::any()) };
+                }
+
+                // pub const fn checked_mul(self, other: Self) -> Option<Self>
+                #[safety::proof_for_contract(NonZero::<$Int>::checked_mul)]
+                fn check_checked_mul() {
+                    let _ = any_nonzero().checked_mul(any_nonzero());
+                }
+
+                // pub const fn saturating_mul(self, other: Self) -> Self
+                #[safety::proof_for_contract(NonZero::<$Int>::saturating_mul)]
+                fn check_saturating_mul() {
+                    let _ = any_nonzero().saturating_mul(any_nonzero());
+                }
+
+                // pub const unsafe fn unchecked_mul(self, other: Self) -> Self
+                #[safety::proof_for_contract(NonZero::<$Int>::unchecked_mul)]
+                fn check_unchecked_mul() {
+                    let _ = unsafe { any_nonzero().unchecked_mul(any_nonzero()) };
+                }
+
+                // pub const fn checked_pow(self, other: u32) -> Option<Self>
+                #[safety::proof_for_contract(NonZero::<$Int>::checked_pow)]
+                fn check_checked_pow() {
+                    let _ = any_nonzero().checked_pow(ub_checks::any());
+                }
+
+                // pub const fn rotate_left(self, n: u32) -> Self
+                #[safety::proof_for_contract(NonZero::<$Int>::rotate_left)]
+                fn check_rotate_left() {
+                    let _ = any_nonzero().rotate_left(ub_checks::any());
+                }
+
+                // pub const fn rotate_right(self, n: u32) -> Self
+                #[safety::proof_for_contract(NonZero::<$Int>::rotate_right)]
+                fn check_rotate_right() {
+                    let _ = any_nonzero().rotate_right(ub_checks::any());
+                }
+
+                // pub const fn swap_bytes(self) -> Self
+                #[safety::proof_for_contract(NonZero::<$Int>::swap_bytes)]
+                fn check_swap_bytes() {
+                    let _ = any_nonzero().swap_bytes();
+                }
+
+                // pub const fn reverse_bits(self) -> Self
+                #[safety::proof_for_contract(NonZero::<$Int>::reverse_bits)]
+                fn check_reverse_bits() {
+                    let _ = any_nonzero().reverse_bits();
+                }
+
+                // pub const fn from_be(x: Self) -> Self
+                #[safety::proof_for_contract(NonZero::<$Int>::from_be)]
+                fn check_from_be() {
+                    let _ = NonZero::<$Int>::from_be(any_nonzero());
+                }
+
+                // pub const fn from_le(x: Self) -> Self
+                #[safety::proof_for_contract(NonZero::<$Int>::from_le)]
+                fn check_from_le() {
+                    let _ = NonZero::<$Int>::from_le(any_nonzero());
+                }
+
+                // fn max(self, other: Self) -> Self
+                #[safety::proof_for_contract(<NonZero<$Int> as Ord>::max)]
+                fn check_max() {
+                    let _ = any_nonzero().max(any_nonzero());
+                }
+
+                // fn min(self, other: Self) -> Self
+                #[safety::proof_for_contract(<NonZero<$Int> as Ord>::min)]
+                fn check_min() {
+                    let _ = any_nonzero().min(any_nonzero());
+                }
+
+                // fn clamp(self, min: Self, max: Self) -> Self
+                #[safety::proof_for_contract(<NonZero<$Int> as Ord>::clamp)]
+                fn check_clamp() {
+                    let (min, max) = (any_nonzero(), any_nonzero());
+                    // `clamp` panics unless `min <= max`.
+                    if min <= max {
+                        let _ = any_nonzero().clamp(min, max);
+                    }
+                }
+
+                // fn bitor(self, rhs: Self) -> Self::Output
+                #[safety::proof_for_contract(<NonZero<$Int> as BitOr>::bitor)]
+                fn check_bitor() {
+                    let _ = any_nonzero() | any_nonzero();
+                }
+
+                // fn bitor(self, rhs: T) -> Self::Output
+                #[safety::proof_for_contract(<NonZero<$Int> as BitOr<$Int>>::bitor)]
+                fn check_bitor_primitive() {
+                    let _ = any_nonzero() | ub_checks::any::<$Int>();
+                }
+
+                // fn bitor(self, rhs: NonZero<T>) -> Self::Output
+                #[safety::proof_for_contract(<$Int as BitOr<NonZero<$Int>>>::bitor)]
+                fn check_primitive_bitor() {
+                    let _ = ub_checks::any::<$Int>() | any_nonzero();
+                }
+
+                $($extra)*
+            }
+        };
+    }
+
+    generate_nonzero_harnesses!(check_i8, i8, signed);
+    generate_nonzero_harnesses!(check_i16, i16, signed);
+    generate_nonzero_harnesses!(check_i32, i32, signed);
+    generate_nonzero_harnesses!(check_i64, i64, signed);
+    generate_nonzero_harnesses!(check_i128, i128, signed);
+    generate_nonzero_harnesses!(check_isize, isize, signed);
+    generate_nonzero_harnesses!(check_u8, u8, unsigned);
+    generate_nonzero_harnesses!(check_u16, u16, unsigned);
+    generate_nonzero_harnesses!(check_u32, u32, unsigned);
+    generate_nonzero_harnesses!(check_u64, u64, unsigned);
+    generate_nonzero_harnesses!(check_u128, u128, unsigned);
+    generate_nonzero_harnesses!(check_usize, usize, unsigned);
+}
@@ -1724,6 +1724,8 @@ impl<T> Option<T> {
     #[inline]
     #[stable(feature = "rust1", since = "1.0.0")]
     #[rustc_const_unstable(feature = "const_option", issue = "67441")]
+    #[safety::ensures(|_| self.is_none())]
+    #[safety::ensures(|result| result.is_some() == old(self.is_some()))]
     pub const fn take(&mut self) -> Option<T> {
         // FIXME replace `mem::replace` by `mem::take` when the latter is const ready
         mem::replace(self, None)
@@ -1782,6 +1784,8 @@ impl<T> Option<T> {
     #[inline]
     #[rustc_const_unstable(feature = "const_option", issue = "67441")]
     #[stable(feature = "option_replace", since = "1.31.0")]
+    #[safety::ensures(|_| self.is_some())]
+    #[safety::ensures(|result| result.is_some() == old(self.is_some()))]
     pub const fn replace(&mut self, value: T) -> Option<T> {
         mem::replace(self, Some(value))
     }
@@ -2553,3 +2557,24 @@ impl<T> Option<Option<T>> {
         }
     }
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+    use crate::ub_checks;
+
+    // pub const fn take(&mut self) -> Option<T>
+    #[safety::proof_for_contract(Option::take)]
+    fn check_take() {
+        let mut x: Option<u32> = ub_checks::any();
+        let _ = x.take();
+    }
+
+    // pub const fn replace(&mut self, value: T) -> Option<T>
+    #[safety::proof_for_contract(Option::replace)]
+    fn check_replace() {
+        let mut x: Option<u32> = ub_checks::any();
+        let _ = x.replace(ub_checks::any());
+    }
+}
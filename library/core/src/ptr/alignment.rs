@@ -1,7 +1,7 @@
 use safety::{ensures, requires};
 use crate::num::NonZero;
 use crate::ub_checks::assert_unsafe_precondition;
-use crate::{cmp, fmt, hash, mem, num};
+use crate::{cmp, fmt, hash, mem, num, ub_checks};
 
 #[cfg(kani)]
 use crate::kani;
@@ -12,6 +12,7 @@ use crate::kani;
 /// Note that particularly large alignments, while representable in this type,
 /// are likely not to be supported by actual allocators and linkers.
 #[unstable(feature = "ptr_alignment_type", issue = "102070")]
+#[safety::invariant(self.as_usize().is_power_of_two())]
 #[derive(Copy, Clone, PartialEq, Eq)]
 #[repr(transparent)]
 pub struct Alignment(AlignmentEnum);
@@ -95,7 +96,9 @@ impl Alignment {
 
         // SAFETY: By precondition, this must be a power of two, and
         // our variants encompass all possible powers of two.
-        unsafe { mem::transmute::<usize, Alignment>(align) }
+        let alignment = unsafe { mem::transmute::<usize, Alignment>(align) };
+        ub_checks::assert_invariant(&alignment);
+        alignment
     }
 
     /// Returns the alignment as a [`usize`].
@@ -383,67 +386,7 @@ enum AlignmentEnum {
     _Align1Shl63 = 1 << 63,
 }
 
+// The harnesses only use the public API, so `coretests` also runs them as unit tests.
 #[cfg(kani)]
 #[unstable(feature="kani", issue="none")]
-mod verify {
-    use super::*;
-
-    impl kani::Arbitrary for Alignment {
-        fn any() -> Self {
-            let align = kani::any_where(|a: &usize| a.is_power_of_two());
-            unsafe { mem::transmute::<usize, Alignment>(align) }
-        }
-    }
-
-    // pub const fn of<T>() -> Self
-    #[kani::proof_for_contract(Alignment::of)]
-    pub fn check_of_i32() {
-        let _ = Alignment::of::<i32>();
-    }
-
-    // pub const fn new(align: usize) -> Option<Self>
-    #[kani::proof_for_contract(Alignment::new)]
-    pub fn check_new() {
-        let a = kani::any::<usize>();
-        let _ = Alignment::new(a);
-    }
-
-    // pub const unsafe fn new_unchecked(align: usize) -> Self
-    #[kani::proof_for_contract(Alignment::new_unchecked)]
-    pub fn check_new_unchecked() {
-        let a = kani::any::<usize>();
-        unsafe {
-            let _ = Alignment::new_unchecked(a);
-        }
-    }
-
-    // pub const fn as_usize(self) -> usize
-    #[kani::proof_for_contract(Alignment::as_usize)]
-    pub fn check_as_usize() {
-        let a = kani::any::<usize>();
-        if let Some(alignment) = Alignment::new(a) {
-            assert_eq!(alignment.as_usize(), a);
-        }
-    }
-
-    // pub const fn as_nonzero(self) -> NonZero<usize>
-    #[kani::proof_for_contract(Alignment::as_nonzero)]
-    pub fn check_as_nonzero() {
-        let alignment = kani::any::<Alignment>();
-        let _ = alignment.as_nonzero();
-    }
-
-    // pub const fn log2(self) -> u32
-    #[kani::proof_for_contract(Alignment::log2)]
-    pub fn check_log2() {
-        let alignment = kani::any::<Alignment>();
-        let _ = alignment.log2();
-    }
-
-    // pub const fn mask(self) -> usize
-    #[kani::proof_for_contract(Alignment::mask)]
-    pub fn check_mask() {
-        let alignment = kani::any::<Alignment>();
-        let _ = alignment.mask();
-    }
-}
+mod verify;
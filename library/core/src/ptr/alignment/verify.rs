@@ -0,0 +1,61 @@
+//! Harnesses for `Alignment`.
+
+use core::ptr::Alignment;
+use core::ub_checks;
+
+/// Returns an arbitrary alignment.
+fn any_alignment() -> Alignment {
+    Alignment::new(1 << ub_checks::any_in(0..usize::BITS)).unwrap()
+}
+
+// pub const fn of<T>() -> Self
+#[safety::proof_for_contract(Alignment::of)]
+pub fn check_of_i32() {
+    let _ = Alignment::of::<i32>();
+}
+
+// pub const fn new(align: usize) -> Option<Self>
+#[safety::proof_for_contract(Alignment::new)]
+pub fn check_new() {
+    let a = ub_checks::any::<usize>();
+    let _ = Alignment::new(a);
+}
+
+// pub const unsafe fn new_unchecked(align: usize) -> Self
+#[safety::proof_for_contract(Alignment::new_unchecked)]
+pub fn check_new_unchecked() {
+    let a = any_alignment().as_usize();
+    unsafe {
+        let _ = Alignment::new_unchecked(a);
+    }
+}
+
+// pub const fn as_usize(self) -> usize
+#[safety::proof_for_contract(Alignment::as_usize)]
+pub fn check_as_usize() {
+    let a = ub_checks::any::<usize>();
+    if let Some(alignment) = Alignment::new(a) {
+        assert_eq!(alignment.as_usize(), a);
+    }
+}
+
+// pub const fn as_nonzero(self) -> NonZero<usize>
+#[safety::proof_for_contract(Alignment::as_nonzero)]
+pub fn check_as_nonzero() {
+    let alignment = any_alignment();
+    let _ = alignment.as_nonzero();
+}
+
+// pub const fn log2(self) -> u32
+#[safety::proof_for_contract(Alignment::log2)]
+pub fn check_log2() {
+    let alignment = any_alignment();
+    let _ = alignment.log2();
+}
+
+// pub const fn mask(self) -> usize
+#[safety::proof_for_contract(Alignment::mask)]
+pub fn check_mask() {
+    let alignment = any_alignment();
+    let _ = alignment.mask();
+}
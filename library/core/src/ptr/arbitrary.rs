@@ -0,0 +1,181 @@
+//! Arbitrary pointers into small arrays, shared by the harnesses of the pointer methods and of the
+//! memory intrinsics.
+
+use crate::{ptr, ub_checks};
+
+/// Length of the arrays the pointers of the harnesses point into.
+pub(crate) const ARRAY_LEN: usize = 4;
+
+/// Returns the index of an arbitrary element of an array, or one past its end.
+pub(crate) fn any_index() -> usize {
+    ub_checks::any_in(0..=ARRAY_LEN)
+}
+
+/// Returns the index of an arbitrary element of an array, excluding the one past its end, so it
+/// can be used to index the array.
+pub(crate) fn any_element_index() -> usize {
+    ub_checks::any_in(0..ARRAY_LEN)
+}
+
+/// Returns an arbitrary number of elements of an array.
+pub(crate) fn any_count() -> usize {
+    ub_checks::any_in(0..=ARRAY_LEN)
+}
+
+/// Returns the index of an arbitrary element of an array that is followed by at least `count`
+/// elements. The contracts already require this unless the elements are zero-sized.
+pub(crate) fn any_start(count: usize) -> usize {
+    ub_checks::any_in(0..=ARRAY_LEN - count)
+}
+
+/// Returns an arbitrary byte offset into an array of `T`, or one past its end, which is not
+/// necessarily aligned.
+pub(crate) fn any_byte_offset<T>() -> usize {
+    ub_checks::any_in(0..=size_of::<[T; ARRAY_LEN]>())
+}
+
+/// Returns an arbitrary byte offset into an array of `T` at which a `T` fits, which is not
+/// necessarily aligned.
+pub(crate) fn any_unaligned_offset<T>() -> usize {
+    ub_checks::any_in(0..=size_of::<[T; ARRAY_LEN - 1]>())
+}
+
+/// Returns a pointer to an arbitrary element of the array `base` points to, or one past its end.
+pub(crate) fn any_element_ptr<T>(base: *mut [T; ARRAY_LEN]) -> *mut T {
+    base.cast::<T>().wrapping_add(any_index())
+}
+
+/// Returns a pointer to an arbitrary byte of the array `base` points to, or one past its end,
+/// which is not necessarily aligned.
+pub(crate) fn any_byte_ptr<T>(base: *mut [T; ARRAY_LEN]) -> *mut T {
+    base.cast::<T>().wrapping_byte_add(any_byte_offset::<T>())
+}
+
+/// Returns a slice pointer with an arbitrary length whose data pointer is an arbitrary byte of
+/// the array `base` points to, since byte offsets also apply to unsized pointees.
+pub(crate) fn any_slice_ptr<T>(base: *mut [T; ARRAY_LEN]) -> *mut [T] {
+    ptr::slice_from_raw_parts_mut(any_byte_ptr(base), any_count())
+}
+
+/// Generates the harnesses of the arithmetic methods of `*const T` or `*mut T`, depending on the
+/// mutability token `$m`.
+macro_rules! generate_arithmetic_harnesses {
+    ($m:tt) => {
+        // `offset_from` panics for zero-sized types, so it has no harness for `()`.
+        $crate::ptr::arbitrary::generate_arithmetic_harnesses!($m, check_unit, (), {});
+        $crate::ptr::arbitrary::generate_arithmetic_harnesses!($m, check_u8, u8);
+        $crate::ptr::arbitrary::generate_arithmetic_harnesses!($m, check_u16, u16);
+        $crate::ptr::arbitrary::generate_arithmetic_harnesses!($m, check_u32, u32);
+        $crate::ptr::arbitrary::generate_arithmetic_harnesses!($m, check_u64, u64);
+        $crate::ptr::arbitrary::generate_arithmetic_harnesses!($m, check_u128, u128);
+        $crate::ptr::arbitrary::generate_arithmetic_harnesses!($m, check_usize, usize);
+        $crate::ptr::arbitrary::generate_arithmetic_harnesses!($m, check_u16_array, [u16; 3]);
+
+        #[safety::proof_for_contract(<*$m [u16]>::byte_add)]
+        fn check_slice_byte_add() {
+            let mut array: [u16; ARRAY_LEN] = ub_checks::any();
+            let ptr = any_slice_ptr(&raw mut array) as *$m [u16];
+            let _ = unsafe { ptr.byte_add(ub_checks::any()) };
+        }
+
+        #[safety::proof_for_contract(<*$m [u16]>::byte_sub)]
+        fn check_slice_byte_sub() {
+            let mut array: [u16; ARRAY_LEN] = ub_checks::any();
+            let ptr = any_slice_ptr(&raw mut array) as *$m [u16];
+            let _ = unsafe { ptr.byte_sub(ub_checks::any()) };
+        }
+
+        #[safety::proof_for_contract(<*$m [u16]>::byte_offset)]
+        fn check_slice_byte_offset() {
+            let mut array: [u16; ARRAY_LEN] = ub_checks::any();
+            let ptr = any_slice_ptr(&raw mut array) as *$m [u16];
+            let _ = unsafe { ptr.byte_offset(ub_checks::any()) };
+        }
+
+        #[safety::proof_for_contract(<*$m [u16]>::byte_offset_from)]
+        fn check_slice_byte_offset_from() {
+            let mut array: [u16; ARRAY_LEN] = ub_checks::any();
+            let base = &raw mut array;
+            let (ptr, origin) = (any_slice_ptr(base) as *$m [u16], any_slice_ptr(base));
+            let _ = unsafe { ptr.byte_offset_from(origin as *const [u16]) };
+        }
+    };
+    ($m:tt, $module:ident, $ty:ty) => {
+        $crate::ptr::arbitrary::generate_arithmetic_harnesses!($m, $module, $ty, {
+            // pub const unsafe fn offset_from(self, origin: *const T) -> isize
+            #[safety::proof_for_contract(<*$m $ty>::offset_from)]
+            fn check_offset_from() {
+                let mut array: [$ty; ARRAY_LEN] = ub_checks::any();
+                let base = &raw mut array;
+                let (ptr, origin) = (any_element_ptr(base) as *$m $ty, any_element_ptr(base));
+                let _ = unsafe { ptr.offset_from(origin) };
+            }
+        });
+    };
+    ($m:tt, $module:ident, $ty:ty, { $($extra:item)* }) => {
+        mod $module {
+            use super::*;
+
+            // pub const unsafe fn add(self, count: usize) -> Self
+            #[safety::proof_for_contract(<*$m $ty>::add)]
+            fn check_add() {
+                let mut array: [$ty; ARRAY_LEN] = ub_checks::any();
+                let ptr = any_element_ptr(&raw mut array) as *$m $ty;
+                let _ = unsafe { ptr.add(ub_checks::any()) };
+            }
+
+            // pub const unsafe fn sub(self, count: usize) -> Self
+            #[safety::proof_for_contract(<*$m $ty>::sub)]
+            fn check_sub() {
+                let mut array: [$ty; ARRAY_LEN] = ub_checks::any();
+                let ptr = any_element_ptr(&raw mut array) as *$m $ty;
+                let _ = unsafe { ptr.sub(ub_checks::any()) };
+            }
+
+            // pub const unsafe fn offset(self, count: isize) -> Self
+            #[safety::proof_for_contract(<*$m $ty>::offset)]
+            fn check_offset() {
+                let mut array: [$ty; ARRAY_LEN] = ub_checks::any();
+                let ptr = any_element_ptr(&raw mut array) as *$m $ty;
+                let _ = unsafe { ptr.offset(ub_checks::any()) };
+            }
+
+            // pub const unsafe fn byte_add(self, count: usize) -> Self
+            #[safety::proof_for_contract(<*$m $ty>::byte_add)]
+            fn check_byte_add() {
+                let mut array: [$ty; ARRAY_LEN] = ub_checks::any();
+                let ptr = any_byte_ptr(&raw mut array) as *$m $ty;
+                let _ = unsafe { ptr.byte_add(ub_checks::any()) };
+            }
+
+            // pub const unsafe fn byte_sub(self, count: usize) -> Self
+            #[safety::proof_for_contract(<*$m $ty>::byte_sub)]
+            fn check_byte_sub() {
+                let mut array: [$ty; ARRAY_LEN] = ub_checks::any();
+                let ptr = any_byte_ptr(&raw mut array) as *$m $ty;
+                let _ = unsafe { ptr.byte_sub(ub_checks::any()) };
+            }
+
+            // pub const unsafe fn byte_offset(self, count: isize) -> Self
+            #[safety::proof_for_contract(<*$m $ty>::byte_offset)]
+            fn check_byte_offset() {
+                let mut array: [$ty; ARRAY_LEN] = ub_checks::any();
+                let ptr = any_byte_ptr(&raw mut array) as *$m $ty;
+                let _ = unsafe { ptr.byte_offset(ub_checks::any()) };
+            }
+
+            // pub const unsafe fn byte_offset_from<U: ?Sized>(self, origin: *const U) -> isize
+            #[safety::proof_for_contract(<*$m $ty>::byte_offset_from)]
+            fn check_byte_offset_from() {
+                let mut array: [$ty; ARRAY_LEN] = ub_checks::any();
+                let base = &raw mut array;
+                let (ptr, origin) = (any_byte_ptr(base) as *$m $ty, any_byte_ptr(base));
+                let _ = unsafe { ptr.byte_offset_from(origin as *const u8) };
+            }
+
+            $($extra)*
+        }
+    };
+}
+
+pub(crate) use generate_arithmetic_harnesses;
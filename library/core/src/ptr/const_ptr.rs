@@ -3,6 +3,7 @@ use crate::cmp::Ordering::{Equal, Greater, Less};
 use crate::intrinsics::const_eval_select;
 use crate::mem::SizedTypeProperties;
 use crate::slice::{self, SliceIndex};
+use safety::{ensures, requires};
 
 impl<T: ?Sized> *const T {
     /// Returns `true` if the pointer is null.
@@ -388,6 +389,13 @@ impl<T: ?Sized> *const T {
     #[rustc_const_stable(feature = "const_ptr_offset", since = "1.61.0")]
     #[inline(always)]
     #[cfg_attr(miri, track_caller)] // even without panics, this helps for Miri backtraces
+    #[requires(
+        count.checked_mul(mem::size_of::<T>() as isize).is_some_and(|bytes| {
+            self.addr().checked_add_signed(bytes).is_some()
+                && (bytes == 0 || ub_checks::same_allocation(self, self.wrapping_offset(count)))
+        })
+    )]
+    #[ensures(|result| *result == self.wrapping_offset(count))]
     pub const unsafe fn offset(self, count: isize) -> *const T
     where
         T: Sized,
@@ -412,6 +420,11 @@ impl<T: ?Sized> *const T {
     #[rustc_const_stable(feature = "const_pointer_byte_offsets", since = "1.75.0")]
     #[rustc_allow_const_fn_unstable(set_ptr_value)]
     #[cfg_attr(miri, track_caller)] // even without panics, this helps for Miri backtraces
+    #[requires(
+        self.addr().checked_add_signed(count).is_some()
+            && (count == 0 || ub_checks::same_allocation(self, self.wrapping_byte_offset(count)))
+    )]
+    #[ensures(|result| crate::ptr::eq(*result, self.wrapping_byte_offset(count)))]
     pub const unsafe fn byte_offset(self, count: isize) -> Self {
         // SAFETY: the caller must uphold the safety contract for `offset`.
         unsafe { self.cast::<u8>().offset(count).with_metadata_of(self) }
@@ -621,6 +634,17 @@ impl<T: ?Sized> *const T {
     #[rustc_const_stable(feature = "const_ptr_offset_from", since = "1.65.0")]
     #[inline]
     #[cfg_attr(miri, track_caller)] // even without panics, this helps for Miri backtraces
+    #[requires(
+        // The body panics for zero-sized types. Otherwise, both pointers must be in bounds of the
+        // same allocated object, and their distance must be a multiple of the size of `T`.
+        mem::size_of::<T>() == 0
+            || (ub_checks::same_allocation(self, origin)
+                && self.addr().abs_diff(origin.addr()) % mem::size_of::<T>() == 0)
+    )]
+    #[ensures(|result| {
+        result.wrapping_mul(mem::size_of::<T>() as isize)
+            == self.addr().wrapping_sub(origin.addr()) as isize
+    })]
     pub const unsafe fn offset_from(self, origin: *const T) -> isize
     where
         T: Sized,
@@ -645,6 +669,8 @@ impl<T: ?Sized> *const T {
     #[rustc_const_stable(feature = "const_pointer_byte_offsets", since = "1.75.0")]
     #[rustc_allow_const_fn_unstable(set_ptr_value)]
     #[cfg_attr(miri, track_caller)] // even without panics, this helps for Miri backtraces
+    #[requires(ub_checks::same_allocation(self.cast::<u8>(), origin.cast::<u8>()))]
+    #[ensures(|result| *result == self.addr().wrapping_sub(origin.addr()) as isize)]
     pub const unsafe fn byte_offset_from<U: ?Sized>(self, origin: *const U) -> isize {
         // SAFETY: the caller must uphold the safety contract for `offset_from`.
         unsafe { self.cast::<u8>().offset_from(origin.cast::<u8>()) }
@@ -849,6 +875,14 @@ impl<T: ?Sized> *const T {
     #[rustc_const_stable(feature = "const_ptr_offset", since = "1.61.0")]
     #[inline(always)]
     #[cfg_attr(miri, track_caller)] // even without panics, this helps for Miri backtraces
+    #[requires(
+        count.checked_mul(mem::size_of::<T>()).is_some_and(|bytes| {
+            bytes <= isize::MAX as usize
+                && self.addr().checked_add(bytes).is_some()
+                && (bytes == 0 || ub_checks::same_allocation(self, self.wrapping_add(count)))
+        })
+    )]
+    #[ensures(|result| *result == self.wrapping_add(count))]
     pub const unsafe fn add(self, count: usize) -> Self
     where
         T: Sized,
@@ -873,6 +907,12 @@ impl<T: ?Sized> *const T {
     #[rustc_const_stable(feature = "const_pointer_byte_offsets", since = "1.75.0")]
     #[rustc_allow_const_fn_unstable(set_ptr_value)]
     #[cfg_attr(miri, track_caller)] // even without panics, this helps for Miri backtraces
+    #[requires(
+        count <= isize::MAX as usize
+            && self.addr().checked_add(count).is_some()
+            && (count == 0 || ub_checks::same_allocation(self, self.wrapping_byte_add(count)))
+    )]
+    #[ensures(|result| crate::ptr::eq(*result, self.wrapping_byte_add(count)))]
     pub const unsafe fn byte_add(self, count: usize) -> Self {
         // SAFETY: the caller must uphold the safety contract for `add`.
         unsafe { self.cast::<u8>().add(count).with_metadata_of(self) }
@@ -924,6 +964,14 @@ impl<T: ?Sized> *const T {
     #[rustc_allow_const_fn_unstable(unchecked_neg)]
     #[inline(always)]
     #[cfg_attr(miri, track_caller)] // even without panics, this helps for Miri backtraces
+    #[requires(
+        count.checked_mul(mem::size_of::<T>()).is_some_and(|bytes| {
+            bytes <= isize::MAX as usize
+                && self.addr().checked_sub(bytes).is_some()
+                && (bytes == 0 || ub_checks::same_allocation(self, self.wrapping_sub(count)))
+        })
+    )]
+    #[ensures(|result| *result == self.wrapping_sub(count))]
     pub const unsafe fn sub(self, count: usize) -> Self
     where
         T: Sized,
@@ -956,6 +1004,12 @@ impl<T: ?Sized> *const T {
     #[rustc_const_stable(feature = "const_pointer_byte_offsets", since = "1.75.0")]
     #[rustc_allow_const_fn_unstable(set_ptr_value)]
     #[cfg_attr(miri, track_caller)] // even without panics, this helps for Miri backtraces
+    #[requires(
+        count <= isize::MAX as usize
+            && self.addr().checked_sub(count).is_some()
+            && (count == 0 || ub_checks::same_allocation(self, self.wrapping_byte_sub(count)))
+    )]
+    #[ensures(|result| crate::ptr::eq(*result, self.wrapping_byte_sub(count)))]
     pub const unsafe fn byte_sub(self, count: usize) -> Self {
         // SAFETY: the caller must uphold the safety contract for `sub`.
         unsafe { self.cast::<u8>().sub(count).with_metadata_of(self) }
@@ -1774,3 +1828,12 @@ impl<T: ?Sized> PartialOrd for *const T {
         *self >= *other
     }
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use crate::ptr::arbitrary::*;
+    use crate::ub_checks;
+
+    generate_arithmetic_harnesses!(const);
+}
@@ -1,6 +1,7 @@
 #![unstable(feature = "ptr_metadata", issue = "81513")]
 
-use crate::fmt;
+use safety::ensures;
+use crate::{fmt, mem, ub_checks};
 use crate::hash::{Hash, Hasher};
 use crate::intrinsics::{aggregate_raw_ptr, ptr_metadata};
 use crate::marker::Freeze;
@@ -94,6 +95,20 @@ pub trait Thin = Pointee<Metadata = ()>;
 /// ```
 #[rustc_const_unstable(feature = "ptr_metadata", issue = "81513")]
 #[inline]
+// Reassembling the pointer from its parts must give back the same pointer.
+#[ensures(|result| {
+    crate::ptr::eq(aggregate_raw_ptr::<*const T, _, _>(ptr.cast::<()>(), *result), ptr)
+})]
+// The size and alignment of the pointee only depend on the metadata, so they are the same for a
+// dangling pointer with the same metadata.
+#[ensures(|result| !ub_checks::has_valid_metadata(ptr) || {
+    let dangling = aggregate_raw_ptr::<*const T, _, _>(crate::ptr::dangling::<()>(), *result);
+    // SAFETY: the metadata is valid, and the layout does not depend on the data pointer.
+    unsafe {
+        mem::size_of_val_raw(dangling) == mem::size_of_val_raw(ptr)
+            && mem::align_of_val_raw(dangling) == mem::align_of_val_raw(ptr)
+    }
+})]
 pub const fn metadata<T: ?Sized>(ptr: *const T) -> <T as Pointee>::Metadata {
     ptr_metadata(ptr)
 }
@@ -108,6 +123,9 @@ pub const fn metadata<T: ?Sized>(ptr: *const T) -> <T as Pointee>::Metadata {
 #[unstable(feature = "ptr_metadata", issue = "81513")]
 #[rustc_const_unstable(feature = "ptr_metadata", issue = "81513")]
 #[inline]
+#[ensures(|result| {
+    result.cast::<()>() == data_pointer.cast::<()>() && crate::ptr::metadata(*result) == metadata
+})]
 pub const fn from_raw_parts<T: ?Sized>(
     data_pointer: *const impl Thin,
     metadata: <T as Pointee>::Metadata,
@@ -122,6 +140,9 @@ pub const fn from_raw_parts<T: ?Sized>(
 #[unstable(feature = "ptr_metadata", issue = "81513")]
 #[rustc_const_unstable(feature = "ptr_metadata", issue = "81513")]
 #[inline]
+#[ensures(|result| {
+    result.cast::<()>() == data_pointer.cast::<()>() && crate::ptr::metadata(*result) == metadata
+})]
 pub const fn from_raw_parts_mut<T: ?Sized>(
     data_pointer: *mut impl Thin,
     metadata: <T as Pointee>::Metadata,
@@ -182,6 +203,7 @@ impl<Dyn: ?Sized> DynMetadata<Dyn> {
 
     /// Returns the size of the type associated with this vtable.
     #[inline]
+    #[ensures(|size| *size <= isize::MAX as usize && *size % self.align_of() == 0)]
     pub fn size_of(self) -> usize {
         // Note that "size stored in vtable" is *not* the same as "result of size_of_val_raw".
         // Consider a reference like `&(i32, dyn Send)`: the vtable will only store the size of the
@@ -192,6 +214,7 @@ impl<Dyn: ?Sized> DynMetadata<Dyn> {
 
     /// Returns the alignment of the type associated with this vtable.
     #[inline]
+    #[ensures(|align| align.is_power_of_two())]
     pub fn align_of(self) -> usize {
         // SAFETY: DynMetadata always contains a valid vtable pointer
         return unsafe { crate::intrinsics::vtable_align(self.vtable_ptr() as *const ()) };
@@ -199,11 +222,31 @@ impl<Dyn: ?Sized> DynMetadata<Dyn> {
 
     /// Returns the size and alignment together as a `Layout`
     #[inline]
+    #[ensures(|result| result.size() == self.size_of() && result.align() == self.align_of())]
+    #[ensures(|result| {
+        let ptr = self.dangling_ptr();
+        // SAFETY: the vtable is valid, and the layout does not depend on the data pointer.
+        unsafe {
+            result.size() == mem::size_of_val_raw(ptr)
+                && result.align() == mem::align_of_val_raw(ptr)
+        }
+    })]
     pub fn layout(self) -> crate::alloc::Layout {
         // SAFETY: the compiler emitted this vtable for a concrete Rust type which
         // is known to have a valid layout. Same rationale as in `Layout::for_value`.
         unsafe { crate::alloc::Layout::from_size_align_unchecked(self.size_of(), self.align_of()) }
     }
+
+    /// Returns a dangling pointer with this metadata, to compute the layout of its pointee with
+    /// `size_of_val_raw` and `align_of_val_raw`.
+    #[inline]
+    fn dangling_ptr(self) -> *const Dyn {
+        // SAFETY: a `DynMetadata<Dyn>` is only created as the metadata of the trait object `Dyn`.
+        let metadata = unsafe {
+            crate::intrinsics::transmute_unchecked::<Self, <Dyn as Pointee>::Metadata>(self)
+        };
+        aggregate_raw_ptr(crate::ptr::dangling::<()>(), metadata)
+    }
 }
 
 unsafe impl<Dyn: ?Sized> Send for DynMetadata<Dyn> {}
@@ -258,3 +301,149 @@ impl<Dyn: ?Sized> Hash for DynMetadata<Dyn> {
         crate::ptr::hash::<VTable, _>(self.vtable_ptr(), hasher)
     }
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+    use crate::{mem, ub_checks};
+
+    /// Length of the arrays that the slices are created from.
+    const ARRAY_LEN: usize = 8;
+
+    /// Returns a pointer to an arbitrary subslice of `array`.
+    fn any_slice_ptr(array: &mut [u32; ARRAY_LEN]) -> *mut [u32] {
+        let start = ub_checks::any_in(0..=ARRAY_LEN);
+        let end = ub_checks::any_in(start..=ARRAY_LEN);
+        &mut array[start..end]
+    }
+
+    /// Returns a pointer to an arbitrary substring of an ASCII string.
+    fn any_str_ptr() -> *const str {
+        const STR: &str = "metadata";
+        let start = ub_checks::any_in(0..=STR.len());
+        let end = ub_checks::any_in(start..=STR.len());
+        &STR[start..end]
+    }
+
+    // pub const fn metadata<T: ?Sized>(ptr: *const T) -> <T as Pointee>::Metadata
+    #[safety::proof_for_contract(metadata::<[u32]>)]
+    pub fn check_metadata_slice() {
+        let mut array: [u32; ARRAY_LEN] = ub_checks::any();
+        let ptr = any_slice_ptr(&mut array);
+        let len = metadata(ptr);
+        assert_eq!(len, ptr.len());
+        assert_eq!(unsafe { mem::size_of_val_raw(ptr) }, len * mem::size_of::<u32>());
+    }
+
+    #[safety::proof_for_contract(metadata::<str>)]
+    pub fn check_metadata_str() {
+        let ptr = any_str_ptr();
+        let len = metadata(ptr);
+        assert_eq!(unsafe { mem::size_of_val_raw(ptr) }, len);
+        assert_eq!(unsafe { mem::align_of_val_raw(ptr) }, 1);
+    }
+
+    // pub const fn from_raw_parts<T: ?Sized>(
+    //     data_pointer: *const impl Thin,
+    //     metadata: <T as Pointee>::Metadata,
+    // ) -> *const T
+    #[safety::proof_for_contract(from_raw_parts::<[u32]>)]
+    pub fn check_from_raw_parts_slice() {
+        let mut array: [u32; ARRAY_LEN] = ub_checks::any();
+        let ptr = any_slice_ptr(&mut array).cast_const();
+        let (data, len) = ptr.to_raw_parts();
+        assert!(crate::ptr::eq(from_raw_parts::<[u32]>(data, len), ptr));
+    }
+
+    #[safety::proof_for_contract(from_raw_parts::<str>)]
+    pub fn check_from_raw_parts_str() {
+        let ptr = any_str_ptr();
+        let (data, len) = ptr.to_raw_parts();
+        assert!(crate::ptr::eq(from_raw_parts::<str>(data, len), ptr));
+    }
+
+    // pub const fn from_raw_parts_mut<T: ?Sized>(
+    //     data_pointer: *mut impl Thin,
+    //     metadata: <T as Pointee>::Metadata,
+    // ) -> *mut T
+    #[safety::proof_for_contract(from_raw_parts_mut::<[u32]>)]
+    pub fn check_from_raw_parts_mut_slice() {
+        let mut array: [u32; ARRAY_LEN] = ub_checks::any();
+        let ptr = any_slice_ptr(&mut array);
+        let (data, len) = ptr.to_raw_parts();
+        assert!(crate::ptr::eq(from_raw_parts_mut::<[u32]>(data, len), ptr));
+    }
+
+    macro_rules! generate_dyn_metadata_harnesses {
+        ($module:ident, $ty:ty) => {
+            mod $module {
+                use super::*;
+
+                // pub const fn metadata<T: ?Sized>(ptr: *const T) -> <T as Pointee>::Metadata
+                #[safety::proof_for_contract(metadata::<dyn fmt::Debug>)]
+                pub fn check_metadata() {
+                    let value: $ty = ub_checks::any();
+                    let ptr: *const dyn fmt::Debug = &value;
+                    let vtable = metadata(ptr);
+                    assert_eq!(unsafe { mem::size_of_val_raw(ptr) }, vtable.size_of());
+                    assert_eq!(unsafe { mem::align_of_val_raw(ptr) }, vtable.align_of());
+                }
+
+                // pub const fn from_raw_parts<T: ?Sized>(
+                //     data_pointer: *const impl Thin,
+                //     metadata: <T as Pointee>::Metadata,
+                // ) -> *const T
+                #[safety::proof_for_contract(from_raw_parts::<dyn fmt::Debug>)]
+                pub fn check_from_raw_parts() {
+                    let value: $ty = ub_checks::any();
+                    let ptr: *const dyn fmt::Debug = &value;
+                    let (data, vtable) = ptr.to_raw_parts();
+                    assert!(crate::ptr::eq(from_raw_parts::<dyn fmt::Debug>(data, vtable), ptr));
+                }
+
+                // pub const fn from_raw_parts_mut<T: ?Sized>(
+                //     data_pointer: *mut impl Thin,
+                //     metadata: <T as Pointee>::Metadata,
+                // ) -> *mut T
+                #[safety::proof_for_contract(from_raw_parts_mut::<dyn fmt::Debug>)]
+                pub fn check_from_raw_parts_mut() {
+                    let mut value: $ty = ub_checks::any();
+                    let ptr: *mut dyn fmt::Debug = &mut value;
+                    let (data, vtable) = ptr.to_raw_parts();
+                    let result = from_raw_parts_mut::<dyn fmt::Debug>(data, vtable);
+                    assert!(crate::ptr::eq(result, ptr));
+                }
+
+                // pub fn size_of(self) -> usize
+                #[safety::proof_for_contract(DynMetadata::<dyn fmt::Debug>::size_of)]
+                pub fn check_size_of() {
+                    let value: $ty = ub_checks::any();
+                    let vtable = metadata(&value as &dyn fmt::Debug);
+                    assert_eq!(vtable.size_of(), mem::size_of::<$ty>());
+                }
+
+                // pub fn align_of(self) -> usize
+                #[safety::proof_for_contract(DynMetadata::<dyn fmt::Debug>::align_of)]
+                pub fn check_align_of() {
+                    let value: $ty = ub_checks::any();
+                    let vtable = metadata(&value as &dyn fmt::Debug);
+                    assert_eq!(vtable.align_of(), mem::align_of::<$ty>());
+                }
+
+                // pub fn layout(self) -> crate::alloc::Layout
+                #[safety::proof_for_contract(DynMetadata::<dyn fmt::Debug>::layout)]
+                pub fn check_layout() {
+                    let value: $ty = ub_checks::any();
+                    let vtable = metadata(&value as &dyn fmt::Debug);
+                    assert_eq!(vtable.layout(), crate::alloc::Layout::new::<$ty>());
+                }
+            }
+        };
+    }
+
+    generate_dyn_metadata_harnesses!(check_unit, ());
+    generate_dyn_metadata_harnesses!(check_u8, u8);
+    generate_dyn_metadata_harnesses!(check_u64, u64);
+    generate_dyn_metadata_harnesses!(check_u16_array, [u16; 3]);
+}
@@ -449,6 +449,7 @@
 use crate::cmp::Ordering;
 use crate::marker::FnPtr;
 use crate::mem::{self, MaybeUninit};
+use crate::ub_checks::Snapshot;
 use crate::{fmt, hash, intrinsics, ub_checks};
 
 #[cfg(kani)]
@@ -483,6 +484,10 @@ pub use unique::Unique;
 mod const_ptr;
 mod mut_ptr;
 
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+pub(crate) mod arbitrary;
+
 /// Executes the destructor (if any) of the pointed-to value.
 ///
 /// This is almost the same as calling [`ptr::read`] and discarding
@@ -574,6 +579,7 @@ mod mut_ptr;
 #[lang = "drop_in_place"]
 #[allow(unconditional_recursion)]
 #[rustc_diagnostic_item = "ptr_drop_in_place"]
+#[safety::requires(ub_checks::can_dereference(to_drop) && ub_checks::can_write(to_drop))]
 pub unsafe fn drop_in_place<T: ?Sized>(to_drop: *mut T) {
     // Code here does not matter - this is replaced by the
     // real drop glue by the compiler.
@@ -954,6 +960,7 @@ pub const fn from_mut<T: ?Sized>(r: &mut T) -> *mut T {
 #[rustc_const_stable(feature = "const_slice_from_raw_parts", since = "1.64.0")]
 #[rustc_allow_const_fn_unstable(ptr_metadata)]
 #[rustc_diagnostic_item = "ptr_slice_from_raw_parts"]
+#[safety::ensures(|result| result.cast::<T>() == data && metadata(*result) == len)]
 pub const fn slice_from_raw_parts<T>(data: *const T, len: usize) -> *const [T] {
     from_raw_parts(data, len)
 }
@@ -1000,6 +1007,7 @@ pub const fn slice_from_raw_parts<T>(data: *const T, len: usize) -> *const [T] {
 #[stable(feature = "slice_from_raw_parts", since = "1.42.0")]
 #[rustc_const_unstable(feature = "const_slice_from_raw_parts_mut", issue = "67456")]
 #[rustc_diagnostic_item = "ptr_slice_from_raw_parts_mut"]
+#[safety::ensures(|result| result.cast::<T>() == data && metadata(*result) == len)]
 pub const fn slice_from_raw_parts_mut<T>(data: *mut T, len: usize) -> *mut [T] {
     from_raw_parts_mut(data, len)
 }
@@ -1079,6 +1087,15 @@ pub const fn slice_from_raw_parts_mut<T>(data: *mut T, len: usize) -> *mut [T] {
 #[stable(feature = "rust1", since = "1.0.0")]
 #[rustc_const_unstable(feature = "const_swap", issue = "83163")]
 #[rustc_diagnostic_item = "ptr_swap"]
+// The swap is untyped, so the values may be uninitialized.
+#[safety::requires(
+    ub_checks::can_dereference(x.cast::<MaybeUninit<T>>())
+        && ub_checks::can_write(x)
+        && ub_checks::can_dereference(y.cast::<MaybeUninit<T>>())
+        && ub_checks::can_write(y)
+)]
+#[safety::modifies(x)]
+#[safety::modifies(y)]
 pub const unsafe fn swap<T>(x: *mut T, y: *mut T) {
     // Give ourselves some scratch space to work with.
     // We do not have to worry about drops: `MaybeUninit` does nothing when dropped.
@@ -1141,6 +1158,19 @@ pub const unsafe fn swap<T>(x: *mut T, y: *mut T) {
 #[stable(feature = "swap_nonoverlapping", since = "1.27.0")]
 #[rustc_const_unstable(feature = "const_swap", issue = "83163")]
 #[rustc_diagnostic_item = "ptr_swap_nonoverlapping"]
+#[safety::requires({
+    // The swap is untyped, so the values may be uninitialized.
+    let x_slice = slice_from_raw_parts_mut(x.cast::<MaybeUninit<T>>(), count);
+    let y_slice = slice_from_raw_parts_mut(y.cast::<MaybeUninit<T>>(), count);
+    ub_checks::is_valid_allocation_size(size_of::<T>(), count)
+        && ub_checks::can_dereference(x_slice)
+        && ub_checks::can_write(x_slice)
+        && ub_checks::can_dereference(y_slice)
+        && ub_checks::can_write(y_slice)
+        && ub_checks::is_nonoverlapping(x as *const (), y as *const (), size_of::<T>(), count)
+})]
+#[safety::modifies(slice_from_raw_parts_mut(x, count))]
+#[safety::modifies(slice_from_raw_parts_mut(y, count))]
 pub const unsafe fn swap_nonoverlapping<T>(x: *mut T, y: *mut T, count: usize) {
     #[allow(unused)]
     macro_rules! attempt_swap_as_chunks {
@@ -1271,6 +1301,13 @@ const unsafe fn swap_nonoverlapping_simple_untyped<T>(x: *mut T, y: *mut T, coun
 #[stable(feature = "rust1", since = "1.0.0")]
 #[rustc_const_unstable(feature = "const_replace", issue = "83164")]
 #[rustc_diagnostic_item = "ptr_replace"]
+#[safety::requires(ub_checks::can_dereference(dst) && ub_checks::can_write(dst))]
+#[safety::modifies(dst)]
+#[safety::ensures(|result| {
+    let old_dst = old(Snapshot::of(unsafe { &*dst }));
+    ub_checks::is_same_value(result, old_dst.get())
+})]
+#[safety::ensures(|_| ub_checks::is_same_value(unsafe { &*dst }, old(Snapshot::of(&src)).get()))]
 pub const unsafe fn replace<T>(dst: *mut T, src: T) -> T {
     // SAFETY: the caller must guarantee that `dst` is valid to be
     // cast to a mutable reference (valid for writes, aligned, initialized),
@@ -1399,6 +1436,8 @@ pub const unsafe fn replace<T>(dst: *mut T, src: T) -> T {
 #[rustc_const_stable(feature = "const_ptr_read", since = "1.71.0")]
 #[cfg_attr(miri, track_caller)] // even without panics, this helps for Miri backtraces
 #[rustc_diagnostic_item = "ptr_read"]
+#[safety::requires(ub_checks::can_dereference(src))]
+#[safety::ensures(|result| ub_checks::is_same_value(result, unsafe { &*src }))]
 pub const unsafe fn read<T>(src: *const T) -> T {
     // It would be semantically correct to implement this via `copy_nonoverlapping`
     // and `MaybeUninit`, as was done before PR #109035. Calling `assume_init`
@@ -1526,6 +1565,9 @@ pub const unsafe fn read<T>(src: *const T) -> T {
 )]
 #[cfg_attr(miri, track_caller)] // even without panics, this helps for Miri backtraces
 #[rustc_diagnostic_item = "ptr_read_unaligned"]
+#[safety::requires(ub_checks::can_read_unaligned(src))]
+// `src` may be unaligned, so it cannot be borrowed to compare the result with `*src`.
+#[safety::ensures(|result| ub_checks::is_valid_value(result))]
 pub const unsafe fn read_unaligned<T>(src: *const T) -> T {
     let mut tmp = MaybeUninit::<T>::uninit();
     // SAFETY: the caller must guarantee that `src` is valid for reads.
@@ -1624,6 +1666,9 @@ pub const unsafe fn read_unaligned<T>(src: *const T) -> T {
 #[rustc_const_unstable(feature = "const_ptr_write", issue = "86302")]
 #[rustc_diagnostic_item = "ptr_write"]
 #[cfg_attr(miri, track_caller)] // even without panics, this helps for Miri backtraces
+#[safety::requires(ub_checks::can_write(dst))]
+#[safety::modifies(dst)]
+#[safety::ensures(|_| ub_checks::is_same_value(unsafe { &*dst }, old(Snapshot::of(&src)).get()))]
 pub const unsafe fn write<T>(dst: *mut T, src: T) {
     // Semantically, it would be fine for this to be implemented as a
     // `copy_nonoverlapping` and appropriate drop suppression of `src`.
@@ -1732,6 +1777,8 @@ pub const unsafe fn write<T>(dst: *mut T, src: T) {
 #[rustc_const_unstable(feature = "const_ptr_write", issue = "86302")]
 #[rustc_diagnostic_item = "ptr_write_unaligned"]
 #[cfg_attr(miri, track_caller)] // even without panics, this helps for Miri backtraces
+#[safety::requires(ub_checks::can_write_unaligned(dst))]
+#[safety::modifies(dst)]
 pub const unsafe fn write_unaligned<T>(dst: *mut T, src: T) {
     // SAFETY: the caller must guarantee that `dst` is valid for writes.
     // `dst` cannot overlap `src` because the caller has mutable access
@@ -1939,12 +1986,12 @@ pub unsafe fn write_volatile<T>(dst: *mut T, src: T) {
         return *result == usize::MAX;
     }
 
-    // Checking if the answer should indeed be usize::MAX when a % stride != 0
-    // requires computing gcd(a, stride), which is too expensive without
-    // quantifiers (https://model-checking.github.io/kani/rfc/rfcs/0010-quantifiers.html).
-    // This should be updated once quantifiers are available.
-    if (a % stride != 0 && *result == usize::MAX) { 
-        return true; 
+    // If the answer is usize::MAX, no offset can align the pointer. Since `a` is a power of two,
+    // offsets only matter modulo `a`, so it is enough to check the first `a` of them.
+    if *result == usize::MAX {
+        return safety::forall!(|k in 0..a| {
+            usize::wrapping_add(usize::wrapping_mul(k, stride), p.addr()) % a != 0
+        });
     }
 
     // If we reach this case, either:
@@ -2492,11 +2539,14 @@ mod verify {
     use crate::fmt::Debug;
     use super::*;
     use crate::kani;
+    use arbitrary::{
+        any_count, any_element_index, any_index, any_start, any_unaligned_offset, ARRAY_LEN,
+    };
     use intrinsics::{
         mul_with_overflow, unchecked_sub, wrapping_mul, wrapping_sub
     };
 
-    #[kani::proof_for_contract(read_volatile)]
+    #[safety::proof_for_contract(read_volatile)]
     pub fn check_read_u128() {
         let val = kani::any::<u16>();
         let ptr = &val as *const _;
@@ -2509,49 +2559,49 @@ mod verify {
         unsafe { align_offset(p, a) };
     }
 
-    #[kani::proof_for_contract(align_offset)]
+    #[safety::proof_for_contract(align_offset)]
     fn check_align_offset_zst() {
         let p = kani::any::<usize>() as *const ();
         check_align_offset(p);
     }
 
-    #[kani::proof_for_contract(align_offset)]
+    #[safety::proof_for_contract(align_offset)]
     fn check_align_offset_u8() {
         let p = kani::any::<usize>() as *const u8;
         check_align_offset(p);
     }
 
-    #[kani::proof_for_contract(align_offset)]
+    #[safety::proof_for_contract(align_offset)]
     fn check_align_offset_u16() {
         let p = kani::any::<usize>() as *const u16;
         check_align_offset(p);
     }
 
-    #[kani::proof_for_contract(align_offset)]
+    #[safety::proof_for_contract(align_offset)]
     fn check_align_offset_u32() {
         let p = kani::any::<usize>() as *const u32;
         check_align_offset(p);
     }
 
-    #[kani::proof_for_contract(align_offset)]
+    #[safety::proof_for_contract(align_offset)]
     fn check_align_offset_u64() {
         let p = kani::any::<usize>() as *const u64;
         check_align_offset(p);
     }
 
-    #[kani::proof_for_contract(align_offset)]
+    #[safety::proof_for_contract(align_offset)]
     fn check_align_offset_u128() {
         let p = kani::any::<usize>() as *const u128;
         check_align_offset(p);
     }
 
-    #[kani::proof_for_contract(align_offset)]
+    #[safety::proof_for_contract(align_offset)]
     fn check_align_offset_4096() {
         let p = kani::any::<usize>() as *const [u128; 64];
         check_align_offset(p);
     }
 
-    #[kani::proof_for_contract(align_offset)]
+    #[safety::proof_for_contract(align_offset)]
     fn check_align_offset_17() {
         let p = kani::any::<usize>() as *const [char; 17];
         check_align_offset(p);
@@ -2560,12 +2610,9 @@ mod verify {
     // This function lives inside align_offset, so it is not publicly accessible (hence this copy).
     #[safety::requires(m.is_power_of_two())]
     #[safety::requires(x < m)]
-    // TODO: add ensures contract to check that the answer is indeed correct
-    // This will require quantifiers (https://model-checking.github.io/kani/rfc/rfcs/0010-quantifiers.html)
-    // so that we can add a precondition that gcd(x, m) = 1 like so:
-    // ∀d, d > 0 ∧ x % d = 0 ∧ m % d = 0 → d = 1
-    // With this precondition, we can then write this postcondition to check the correctness of the answer:
-    // #[safety::ensures(|result| wrapping_mul(*result, x) % m == 1)]
+    // gcd(x, m) = 1, which holds for a power of two `m` iff `x` is odd, or `m` is 1.
+    #[safety::requires(m == 1 || x % 2 == 1)]
+    #[safety::ensures(|result| wrapping_mul(*result, x) % m == 1 % m)]
     const unsafe fn mod_inv_copy(x: usize, m: usize) -> usize {
         /// Multiplicative modular inverse table modulo 2⁴ = 16.
         ///
@@ -2613,10 +2660,139 @@ mod verify {
 
     // TODO: Once https://github.com/model-checking/kani/issues/3467 is fixed,
     // move this harness inside `align_offset` and delete `mod_inv_copy`
-    #[kani::proof_for_contract(mod_inv_copy)]
+    #[safety::proof_for_contract(mod_inv_copy)]
     fn check_mod_inv() {
         let x = kani::any::<usize>();
         let m = kani::any::<usize>();
         unsafe { mod_inv_copy(x, m) };
     }
+
+    /// A type with a padding byte.
+    #[derive(Clone, Copy, PartialEq, Debug)]
+    struct Padded {
+        byte: u8,
+        half: u16,
+    }
+
+    impl ub_checks::Arbitrary for Padded {
+        fn any() -> Self {
+            Padded { byte: ub_checks::any(), half: ub_checks::any() }
+        }
+    }
+
+    macro_rules! generate_access_harnesses {
+        ($module:ident, $ty:ty) => {
+            mod $module {
+                use super::*;
+
+                // pub const unsafe fn read<T>(src: *const T) -> T
+                #[safety::proof_for_contract(read::<$ty>)]
+                fn check_read() {
+                    let array: [$ty; ARRAY_LEN] = ub_checks::any();
+                    let idx = any_element_index();
+                    let value = unsafe { read(array.as_ptr().wrapping_add(idx)) };
+                    assert_eq!(value, array[idx]);
+                }
+
+                // pub const unsafe fn read_unaligned<T>(src: *const T) -> T
+                #[safety::proof_for_contract(read_unaligned::<$ty>)]
+                fn check_read_unaligned() {
+                    let mut array: [$ty; ARRAY_LEN] = ub_checks::any();
+                    let ptr = array.as_mut_ptr().wrapping_byte_add(any_unaligned_offset::<$ty>());
+                    let value: $ty = ub_checks::any();
+                    unsafe { ptr.write_unaligned(value) };
+                    assert_eq!(unsafe { read_unaligned(ptr) }, value);
+                }
+
+                // pub const unsafe fn write<T>(dst: *mut T, src: T)
+                #[safety::proof_for_contract(write::<$ty>)]
+                fn check_write() {
+                    let mut array: [$ty; ARRAY_LEN] = ub_checks::any();
+                    let mut expected = array;
+                    let (idx, value) = (any_element_index(), ub_checks::any());
+                    unsafe { write(array.as_mut_ptr().wrapping_add(idx), value) };
+                    expected[idx] = value;
+                    assert_eq!(array, expected);
+                }
+
+                // pub const unsafe fn write_unaligned<T>(dst: *mut T, src: T)
+                #[safety::proof_for_contract(write_unaligned::<$ty>)]
+                fn check_write_unaligned() {
+                    let mut array: [$ty; ARRAY_LEN] = ub_checks::any();
+                    let ptr = array.as_mut_ptr().wrapping_byte_add(ub_checks::any());
+                    let value: $ty = ub_checks::any();
+                    unsafe { write_unaligned(ptr, value) };
+                    assert_eq!(unsafe { ptr.read_unaligned() }, value);
+                }
+
+                // pub const unsafe fn replace<T>(dst: *mut T, src: T) -> T
+                #[safety::proof_for_contract(replace::<$ty>)]
+                fn check_replace() {
+                    let mut array: [$ty; ARRAY_LEN] = ub_checks::any();
+                    let old_array = array;
+                    let (idx, value) = (any_element_index(), ub_checks::any());
+                    let old = unsafe { replace(array.as_mut_ptr().wrapping_add(idx), value) };
+                    assert_eq!(old, old_array[idx]);
+                    assert_eq!(array[idx], value);
+                }
+
+                // pub unsafe fn drop_in_place<T: ?Sized>(to_drop: *mut T)
+                #[safety::proof_for_contract(drop_in_place::<$ty>)]
+                fn check_drop_in_place() {
+                    let mut array: [$ty; ARRAY_LEN] = ub_checks::any();
+                    let idx = any_element_index();
+                    unsafe { drop_in_place(array.as_mut_ptr().wrapping_add(idx)) };
+                }
+
+                // pub const unsafe fn swap<T>(x: *mut T, y: *mut T)
+                #[safety::proof_for_contract(swap::<$ty>)]
+                fn check_swap() {
+                    let mut array: [$ty; ARRAY_LEN] = ub_checks::any();
+                    let old_array = array;
+                    let (x_idx, y_idx) = (any_element_index(), any_element_index());
+                    let base = array.as_mut_ptr();
+                    unsafe { swap(base.wrapping_add(x_idx), base.wrapping_add(y_idx)) };
+                    assert_eq!(array[x_idx], old_array[y_idx]);
+                    assert_eq!(array[y_idx], old_array[x_idx]);
+                }
+
+                // pub const unsafe fn swap_nonoverlapping<T>(x: *mut T, y: *mut T, count: usize)
+                #[safety::proof_for_contract(swap_nonoverlapping::<$ty>)]
+                fn check_swap_nonoverlapping() {
+                    let mut x: [$ty; ARRAY_LEN] = ub_checks::any();
+                    let mut y: [$ty; ARRAY_LEN] = ub_checks::any();
+                    let (old_x, old_y) = (x, y);
+                    let count = any_count();
+                    let (x_idx, y_idx) = (any_start(count), any_start(count));
+                    let x_ptr = x.as_mut_ptr().wrapping_add(x_idx);
+                    let y_ptr = y.as_mut_ptr().wrapping_add(y_idx);
+                    unsafe { swap_nonoverlapping(x_ptr, y_ptr, count) };
+                    assert_eq!(x[x_idx..][..count], old_y[y_idx..][..count]);
+                    assert_eq!(y[y_idx..][..count], old_x[x_idx..][..count]);
+                }
+
+                // pub const fn slice_from_raw_parts<T>(data: *const T, len: usize) -> *const [T]
+                #[safety::proof_for_contract(slice_from_raw_parts::<$ty>)]
+                fn check_slice_from_raw_parts() {
+                    let array: [$ty; ARRAY_LEN] = ub_checks::any();
+                    let data = array.as_ptr().wrapping_add(any_index());
+                    let _ = slice_from_raw_parts(data, ub_checks::any());
+                }
+
+                // pub const fn slice_from_raw_parts_mut<T>(data: *mut T, len: usize) -> *mut [T]
+                #[safety::proof_for_contract(slice_from_raw_parts_mut::<$ty>)]
+                fn check_slice_from_raw_parts_mut() {
+                    let mut array: [$ty; ARRAY_LEN] = ub_checks::any();
+                    let data = array.as_mut_ptr().wrapping_add(any_index());
+                    let _ = slice_from_raw_parts_mut(data, ub_checks::any());
+                }
+            }
+        };
+    }
+
+    generate_access_harnesses!(check_unit, ());
+    generate_access_harnesses!(check_u8, u8);
+    generate_access_harnesses!(check_u128, u128);
+    generate_access_harnesses!(check_padded, Padded);
+    generate_access_harnesses!(check_option, Option<u8>);
 }
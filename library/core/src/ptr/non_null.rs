@@ -1,3 +1,4 @@
+use safety::{ensures, requires};
 use crate::cmp::Ordering;
 use crate::marker::Unsize;
 use crate::mem::{MaybeUninit, SizedTypeProperties};
@@ -7,7 +8,10 @@ use crate::pin::PinCoerceUnsized;
 use crate::ptr::Unique;
 use crate::slice::{self, SliceIndex};
 use crate::ub_checks::assert_unsafe_precondition;
-use crate::{fmt, hash, intrinsics, ptr};
+use crate::{fmt, hash, intrinsics, ptr, ub_checks};
+
+#[cfg(kani)]
+use crate::kani;
 
 /// `*mut T` but non-zero and [covariant].
 ///
@@ -68,6 +72,7 @@ use crate::{fmt, hash, intrinsics, ptr};
 #[rustc_layout_scalar_valid_range_start(1)]
 #[rustc_nonnull_optimization_guaranteed]
 #[rustc_diagnostic_item = "NonNull"]
+#[safety::invariant(!self.pointer.is_null())]
 pub struct NonNull<T: ?Sized> {
     pointer: *const T,
 }
@@ -106,6 +111,7 @@ impl<T: Sized> NonNull<T> {
     #[rustc_const_stable(feature = "const_nonnull_dangling", since = "1.36.0")]
     #[must_use]
     #[inline]
+    #[ensures(|result| result.is_aligned())]
     pub const fn dangling() -> Self {
         // SAFETY: mem::align_of() returns a non-zero usize which is then casted
         // to a *mut T. Therefore, `ptr` is not null and the conditions for
@@ -134,6 +140,8 @@ impl<T: Sized> NonNull<T> {
     #[must_use]
     #[unstable(feature = "ptr_as_uninit", issue = "75402")]
     #[rustc_const_unstable(feature = "const_ptr_as_ref", issue = "91822")]
+    #[requires(ub_checks::can_dereference(self.cast::<MaybeUninit<T>>().as_ptr()))]
+    #[ensures(|result| ptr::eq(*result, self.cast::<MaybeUninit<T>>().as_ptr()))]
     pub const unsafe fn as_uninit_ref<'a>(self) -> &'a MaybeUninit<T> {
         // SAFETY: the caller must guarantee that `self` meets all the
         // requirements for a reference.
@@ -158,6 +166,8 @@ impl<T: Sized> NonNull<T> {
     #[must_use]
     #[unstable(feature = "ptr_as_uninit", issue = "75402")]
     #[rustc_const_unstable(feature = "const_ptr_as_ref", issue = "91822")]
+    #[requires(ub_checks::can_write(self.cast::<MaybeUninit<T>>().as_ptr()))]
+    #[ensures(|result| ptr::eq(&**result, self.cast::<MaybeUninit<T>>().as_ptr()))]
     pub const unsafe fn as_uninit_mut<'a>(self) -> &'a mut MaybeUninit<T> {
         // SAFETY: the caller must guarantee that `self` meets all the
         // requirements for a reference.
@@ -192,6 +202,8 @@ impl<T: ?Sized> NonNull<T> {
     #[stable(feature = "nonnull", since = "1.25.0")]
     #[rustc_const_stable(feature = "const_nonnull_new_unchecked", since = "1.25.0")]
     #[inline]
+    #[requires(!ptr.is_null())]
+    #[ensures(|result| ptr::eq(result.as_ptr(), ptr))]
     pub const unsafe fn new_unchecked(ptr: *mut T) -> Self {
         // SAFETY: the caller must guarantee that `ptr` is non-null.
         unsafe {
@@ -221,6 +233,10 @@ impl<T: ?Sized> NonNull<T> {
     #[stable(feature = "nonnull", since = "1.25.0")]
     #[rustc_const_unstable(feature = "const_nonnull_new", issue = "93235")]
     #[inline]
+    #[ensures(|result| match result {
+        Some(non_null) => ptr::eq(non_null.as_ptr(), ptr),
+        None => ptr.is_null(),
+    })]
     pub const fn new(ptr: *mut T) -> Option<Self> {
         if !ptr.is_null() {
             // SAFETY: The pointer is already checked and is not null
@@ -239,6 +255,9 @@ impl<T: ?Sized> NonNull<T> {
     #[unstable(feature = "ptr_metadata", issue = "81513")]
     #[rustc_const_unstable(feature = "ptr_metadata", issue = "81513")]
     #[inline]
+    #[ensures(|result| {
+        result.cast::<()>() == data_pointer && ptr::metadata(result.as_ptr()) == metadata
+    })]
     pub const fn from_raw_parts(
         data_pointer: NonNull<()>,
         metadata: <T as super::Pointee>::Metadata,
@@ -257,6 +276,7 @@ impl<T: ?Sized> NonNull<T> {
     #[must_use = "this returns the result of the operation, \
                   without modifying the original"]
     #[inline]
+    #[ensures(|result| result.0 == self.cast::<()>() && result.1 == ptr::metadata(self.as_ptr()))]
     pub const fn to_raw_parts(self) -> (NonNull<()>, <T as super::Pointee>::Metadata) {
         (self.cast(), super::metadata(self.as_ptr()))
     }
@@ -270,6 +290,7 @@ impl<T: ?Sized> NonNull<T> {
     #[must_use]
     #[inline]
     #[unstable(feature = "strict_provenance", issue = "95228")]
+    #[ensures(|result| result.get() == self.as_ptr().addr())]
     pub fn addr(self) -> NonZero<usize> {
         // SAFETY: The pointer is guaranteed by the type to be non-null,
         // meaning that the address will be non-zero.
@@ -285,6 +306,9 @@ impl<T: ?Sized> NonNull<T> {
     #[must_use]
     #[inline]
     #[unstable(feature = "strict_provenance", issue = "95228")]
+    #[ensures(|result| {
+        result.addr() == addr && ptr::metadata(result.as_ptr()) == ptr::metadata(self.as_ptr())
+    })]
     pub fn with_addr(self, addr: NonZero<usize>) -> Self {
         // SAFETY: The result of `ptr::from::with_addr` is non-null because `addr` is guaranteed to be non-zero.
         unsafe { NonNull::new_unchecked(self.pointer.with_addr(addr.get()) as *mut _) }
@@ -299,6 +323,7 @@ impl<T: ?Sized> NonNull<T> {
     #[must_use]
     #[inline]
     #[unstable(feature = "strict_provenance", issue = "95228")]
+    #[ensures(|result| ptr::metadata(result.as_ptr()) == ptr::metadata(self.as_ptr()))]
     pub fn map_addr(self, f: impl FnOnce(NonZero<usize>) -> NonZero<usize>) -> Self {
         self.with_addr(f(self.addr()))
     }
@@ -325,6 +350,7 @@ impl<T: ?Sized> NonNull<T> {
     #[rustc_never_returns_null_ptr]
     #[must_use]
     #[inline(always)]
+    #[ensures(|result| !result.is_null())]
     pub const fn as_ptr(self) -> *mut T {
         self.pointer as *mut T
     }
@@ -359,6 +385,8 @@ impl<T: ?Sized> NonNull<T> {
     #[rustc_const_stable(feature = "const_nonnull_as_ref", since = "1.73.0")]
     #[must_use]
     #[inline(always)]
+    #[requires(ub_checks::can_dereference(self.as_ptr()))]
+    #[ensures(|result| ptr::eq(*result, self.as_ptr()))]
     pub const unsafe fn as_ref<'a>(&self) -> &'a T {
         // SAFETY: the caller must guarantee that `self` meets all the
         // requirements for a reference.
@@ -397,6 +425,8 @@ impl<T: ?Sized> NonNull<T> {
     #[rustc_const_unstable(feature = "const_ptr_as_ref", issue = "91822")]
     #[must_use]
     #[inline(always)]
+    #[requires(ub_checks::can_dereference(self.as_ptr()) && ub_checks::can_write(self.as_ptr()))]
+    #[ensures(|result| ptr::eq(&**result, self.as_ptr()))]
     pub const unsafe fn as_mut<'a>(&mut self) -> &'a mut T {
         // SAFETY: the caller must guarantee that `self` meets all the
         // requirements for a mutable reference.
@@ -421,6 +451,7 @@ impl<T: ?Sized> NonNull<T> {
     #[must_use = "this returns the result of the operation, \
                   without modifying the original"]
     #[inline]
+    #[ensures(|result| result.as_ptr().addr() == self.as_ptr().addr())]
     pub const fn cast<U>(self) -> NonNull<U> {
         // SAFETY: `self` is a `NonNull` pointer which is necessarily non-null
         unsafe { NonNull { pointer: self.as_ptr() as *mut U } }
@@ -467,6 +498,17 @@ impl<T: ?Sized> NonNull<T> {
     #[must_use = "returns a new pointer rather than modifying its argument"]
     #[stable(feature = "non_null_convenience", since = "1.80.0")]
     #[rustc_const_stable(feature = "non_null_convenience", since = "1.80.0")]
+    #[requires(
+        count.checked_mul(size_of::<T>() as isize).is_some_and(|bytes| {
+            self.pointer.addr().checked_add_signed(bytes).is_some()
+                && (bytes == 0
+                    || ub_checks::same_allocation(
+                        self.pointer,
+                        self.pointer.wrapping_offset(count),
+                    ))
+        })
+    )]
+    #[ensures(|result| result.pointer == self.pointer.wrapping_offset(count))]
     pub const unsafe fn offset(self, count: isize) -> Self
     where
         T: Sized,
@@ -493,6 +535,15 @@ impl<T: ?Sized> NonNull<T> {
     #[cfg_attr(miri, track_caller)] // even without panics, this helps for Miri backtraces
     #[stable(feature = "non_null_convenience", since = "1.80.0")]
     #[rustc_const_stable(feature = "non_null_convenience", since = "1.80.0")]
+    #[requires(
+        self.pointer.addr().checked_add_signed(count).is_some()
+            && (count == 0
+                || ub_checks::same_allocation(
+                    self.pointer,
+                    self.pointer.wrapping_byte_offset(count),
+                ))
+    )]
+    #[ensures(|result| ptr::eq(result.pointer, self.pointer.wrapping_byte_offset(count)))]
     pub const unsafe fn byte_offset(self, count: isize) -> Self {
         // SAFETY: the caller must uphold the safety contract for `offset` and `byte_offset` has
         // the same safety contract.
@@ -543,6 +594,15 @@ impl<T: ?Sized> NonNull<T> {
     #[must_use = "returns a new pointer rather than modifying its argument"]
     #[stable(feature = "non_null_convenience", since = "1.80.0")]
     #[rustc_const_stable(feature = "non_null_convenience", since = "1.80.0")]
+    #[requires(
+        count.checked_mul(size_of::<T>()).is_some_and(|bytes| {
+            bytes <= isize::MAX as usize
+                && self.pointer.addr().checked_add(bytes).is_some()
+                && (bytes == 0
+                    || ub_checks::same_allocation(self.pointer, self.pointer.wrapping_add(count)))
+        })
+    )]
+    #[ensures(|result| result.pointer == self.pointer.wrapping_add(count))]
     pub const unsafe fn add(self, count: usize) -> Self
     where
         T: Sized,
@@ -570,6 +630,13 @@ impl<T: ?Sized> NonNull<T> {
     #[rustc_allow_const_fn_unstable(set_ptr_value)]
     #[stable(feature = "non_null_convenience", since = "1.80.0")]
     #[rustc_const_stable(feature = "non_null_convenience", since = "1.80.0")]
+    #[requires(
+        count <= isize::MAX as usize
+            && self.pointer.addr().checked_add(count).is_some()
+            && (count == 0
+                || ub_checks::same_allocation(self.pointer, self.pointer.wrapping_byte_add(count)))
+    )]
+    #[ensures(|result| ptr::eq(result.pointer, self.pointer.wrapping_byte_add(count)))]
     pub const unsafe fn byte_add(self, count: usize) -> Self {
         // SAFETY: the caller must uphold the safety contract for `add` and `byte_add` has the same
         // safety contract.
@@ -622,6 +689,15 @@ impl<T: ?Sized> NonNull<T> {
     #[stable(feature = "non_null_convenience", since = "1.80.0")]
     #[rustc_const_stable(feature = "non_null_convenience", since = "1.80.0")]
     #[rustc_allow_const_fn_unstable(unchecked_neg)]
+    #[requires(
+        count.checked_mul(size_of::<T>()).is_some_and(|bytes| {
+            bytes <= isize::MAX as usize
+                && self.pointer.addr().checked_sub(bytes).is_some()
+                && (bytes == 0
+                    || ub_checks::same_allocation(self.pointer, self.pointer.wrapping_sub(count)))
+        })
+    )]
+    #[ensures(|result| result.pointer == self.pointer.wrapping_sub(count))]
     pub const unsafe fn sub(self, count: usize) -> Self
     where
         T: Sized,
@@ -654,6 +730,13 @@ impl<T: ?Sized> NonNull<T> {
     #[rustc_allow_const_fn_unstable(set_ptr_value)]
     #[stable(feature = "non_null_convenience", since = "1.80.0")]
     #[rustc_const_stable(feature = "non_null_convenience", since = "1.80.0")]
+    #[requires(
+        count <= isize::MAX as usize
+            && self.pointer.addr().checked_sub(count).is_some()
+            && (count == 0
+                || ub_checks::same_allocation(self.pointer, self.pointer.wrapping_byte_sub(count)))
+    )]
+    #[ensures(|result| ptr::eq(result.pointer, self.pointer.wrapping_byte_sub(count)))]
     pub const unsafe fn byte_sub(self, count: usize) -> Self {
         // SAFETY: the caller must uphold the safety contract for `sub` and `byte_sub` has the same
         // safety contract.
@@ -753,6 +836,17 @@ impl<T: ?Sized> NonNull<T> {
     #[cfg_attr(miri, track_caller)] // even without panics, this helps for Miri backtraces
     #[stable(feature = "non_null_convenience", since = "1.80.0")]
     #[rustc_const_stable(feature = "non_null_convenience", since = "1.80.0")]
+    #[requires(
+        // The body panics for zero-sized types. Otherwise, both pointers must be in bounds of the
+        // same allocated object, and their distance must be a multiple of the size of `T`.
+        size_of::<T>() == 0
+            || (ub_checks::same_allocation(self.pointer, origin.pointer)
+                && self.pointer.addr().abs_diff(origin.pointer.addr()) % size_of::<T>() == 0)
+    )]
+    #[ensures(|result| {
+        result.wrapping_mul(size_of::<T>() as isize)
+            == self.pointer.addr().wrapping_sub(origin.pointer.addr()) as isize
+    })]
     pub const unsafe fn offset_from(self, origin: NonNull<T>) -> isize
     where
         T: Sized,
@@ -774,6 +868,8 @@ impl<T: ?Sized> NonNull<T> {
     #[cfg_attr(miri, track_caller)] // even without panics, this helps for Miri backtraces
     #[stable(feature = "non_null_convenience", since = "1.80.0")]
     #[rustc_const_stable(feature = "non_null_convenience", since = "1.80.0")]
+    #[requires(ub_checks::same_allocation(self.pointer.cast::<u8>(), origin.pointer.cast::<u8>()))]
+    #[ensures(|result| *result == self.pointer.addr().wrapping_sub(origin.pointer.addr()) as isize)]
     pub const unsafe fn byte_offset_from<U: ?Sized>(self, origin: NonNull<U>) -> isize {
         // SAFETY: the caller must uphold the safety contract for `byte_offset_from`.
         unsafe { self.pointer.byte_offset_from(origin.pointer) }
@@ -846,6 +942,19 @@ impl<T: ?Sized> NonNull<T> {
     #[cfg_attr(miri, track_caller)] // even without panics, this helps for Miri backtraces
     #[unstable(feature = "ptr_sub_ptr", issue = "95892")]
     #[rustc_const_unstable(feature = "const_ptr_sub_ptr", issue = "95892")]
+    #[requires(
+        // The body panics for zero-sized types. Otherwise, both pointers must be in bounds of the
+        // same allocated object, `self` must not be below `subtracted`, and their distance must
+        // be a multiple of the size of `T`.
+        size_of::<T>() == 0
+            || (ub_checks::same_allocation(self.pointer, subtracted.pointer)
+                && self.pointer.addr() >= subtracted.pointer.addr()
+                && (self.pointer.addr() - subtracted.pointer.addr()) % size_of::<T>() == 0)
+    )]
+    #[ensures(|result| {
+        result.wrapping_mul(size_of::<T>())
+            == self.pointer.addr().wrapping_sub(subtracted.pointer.addr())
+    })]
     pub const unsafe fn sub_ptr(self, subtracted: NonNull<T>) -> usize
     where
         T: Sized,
@@ -864,6 +973,7 @@ impl<T: ?Sized> NonNull<T> {
     #[cfg_attr(miri, track_caller)] // even without panics, this helps for Miri backtraces
     #[stable(feature = "non_null_convenience", since = "1.80.0")]
     #[rustc_const_stable(feature = "non_null_convenience", since = "1.80.0")]
+    #[requires(ub_checks::can_dereference(self.pointer))]
     pub const unsafe fn read(self) -> T
     where
         T: Sized,
@@ -885,6 +995,7 @@ impl<T: ?Sized> NonNull<T> {
     #[inline]
     #[cfg_attr(miri, track_caller)] // even without panics, this helps for Miri backtraces
     #[stable(feature = "non_null_convenience", since = "1.80.0")]
+    #[requires(ub_checks::can_dereference(self.pointer))]
     pub unsafe fn read_volatile(self) -> T
     where
         T: Sized,
@@ -905,6 +1016,7 @@ impl<T: ?Sized> NonNull<T> {
     #[cfg_attr(miri, track_caller)] // even without panics, this helps for Miri backtraces
     #[stable(feature = "non_null_convenience", since = "1.80.0")]
     #[rustc_const_stable(feature = "non_null_convenience", since = "1.80.0")]
+    #[requires(ub_checks::can_read_unaligned(self.pointer))]
     pub const unsafe fn read_unaligned(self) -> T
     where
         T: Sized,
@@ -925,6 +1037,11 @@ impl<T: ?Sized> NonNull<T> {
     #[cfg_attr(miri, track_caller)] // even without panics, this helps for Miri backtraces
     #[stable(feature = "non_null_convenience", since = "1.80.0")]
     #[rustc_const_unstable(feature = "const_intrinsic_copy", issue = "80697")]
+    #[requires(
+        ub_checks::is_valid_allocation_size(size_of::<T>(), count)
+            && ub_checks::can_dereference(ptr::slice_from_raw_parts(self.pointer, count))
+            && ub_checks::can_write(ptr::slice_from_raw_parts_mut(dest.as_ptr(), count))
+    )]
     pub const unsafe fn copy_to(self, dest: NonNull<T>, count: usize)
     where
         T: Sized,
@@ -945,6 +1062,17 @@ impl<T: ?Sized> NonNull<T> {
     #[cfg_attr(miri, track_caller)] // even without panics, this helps for Miri backtraces
     #[stable(feature = "non_null_convenience", since = "1.80.0")]
     #[rustc_const_unstable(feature = "const_intrinsic_copy", issue = "80697")]
+    #[requires(
+        ub_checks::is_valid_allocation_size(size_of::<T>(), count)
+            && ub_checks::can_dereference(ptr::slice_from_raw_parts(self.pointer, count))
+            && ub_checks::can_write(ptr::slice_from_raw_parts_mut(dest.as_ptr(), count))
+            && ub_checks::is_nonoverlapping(
+                self.pointer as *const (),
+                dest.pointer as *const (),
+                size_of::<T>(),
+                count,
+            )
+    )]
     pub const unsafe fn copy_to_nonoverlapping(self, dest: NonNull<T>, count: usize)
     where
         T: Sized,
@@ -965,6 +1093,11 @@ impl<T: ?Sized> NonNull<T> {
     #[cfg_attr(miri, track_caller)] // even without panics, this helps for Miri backtraces
     #[stable(feature = "non_null_convenience", since = "1.80.0")]
     #[rustc_const_unstable(feature = "const_intrinsic_copy", issue = "80697")]
+    #[requires(
+        ub_checks::is_valid_allocation_size(size_of::<T>(), count)
+            && ub_checks::can_dereference(ptr::slice_from_raw_parts(src.pointer, count))
+            && ub_checks::can_write(ptr::slice_from_raw_parts_mut(self.as_ptr(), count))
+    )]
     pub const unsafe fn copy_from(self, src: NonNull<T>, count: usize)
     where
         T: Sized,
@@ -985,6 +1118,17 @@ impl<T: ?Sized> NonNull<T> {
     #[cfg_attr(miri, track_caller)] // even without panics, this helps for Miri backtraces
     #[stable(feature = "non_null_convenience", since = "1.80.0")]
     #[rustc_const_unstable(feature = "const_intrinsic_copy", issue = "80697")]
+    #[requires(
+        ub_checks::is_valid_allocation_size(size_of::<T>(), count)
+            && ub_checks::can_dereference(ptr::slice_from_raw_parts(src.pointer, count))
+            && ub_checks::can_write(ptr::slice_from_raw_parts_mut(self.as_ptr(), count))
+            && ub_checks::is_nonoverlapping(
+                src.pointer as *const (),
+                self.pointer as *const (),
+                size_of::<T>(),
+                count,
+            )
+    )]
     pub const unsafe fn copy_from_nonoverlapping(self, src: NonNull<T>, count: usize)
     where
         T: Sized,
@@ -1000,6 +1144,7 @@ impl<T: ?Sized> NonNull<T> {
     /// [`ptr::drop_in_place`]: crate::ptr::drop_in_place()
     #[inline(always)]
     #[stable(feature = "non_null_convenience", since = "1.80.0")]
+    #[requires(ub_checks::can_dereference(self.pointer) && ub_checks::can_write(self.as_ptr()))]
     pub unsafe fn drop_in_place(self) {
         // SAFETY: the caller must uphold the safety contract for `drop_in_place`.
         unsafe { ptr::drop_in_place(self.as_ptr()) }
@@ -1015,6 +1160,7 @@ impl<T: ?Sized> NonNull<T> {
     #[cfg_attr(miri, track_caller)] // even without panics, this helps for Miri backtraces
     #[stable(feature = "non_null_convenience", since = "1.80.0")]
     #[rustc_const_unstable(feature = "const_ptr_write", issue = "86302")]
+    #[requires(ub_checks::can_write(self.as_ptr()))]
     pub const unsafe fn write(self, val: T)
     where
         T: Sized,
@@ -1034,6 +1180,10 @@ impl<T: ?Sized> NonNull<T> {
     #[cfg_attr(miri, track_caller)] // even without panics, this helps for Miri backtraces
     #[stable(feature = "non_null_convenience", since = "1.80.0")]
     #[rustc_const_unstable(feature = "const_ptr_write", issue = "86302")]
+    #[requires(
+        ub_checks::is_valid_allocation_size(size_of::<T>(), count)
+            && ub_checks::can_write(ptr::slice_from_raw_parts_mut(self.as_ptr(), count))
+    )]
     pub const unsafe fn write_bytes(self, val: u8, count: usize)
     where
         T: Sized,
@@ -1055,6 +1205,7 @@ impl<T: ?Sized> NonNull<T> {
     #[inline(always)]
     #[cfg_attr(miri, track_caller)] // even without panics, this helps for Miri backtraces
     #[stable(feature = "non_null_convenience", since = "1.80.0")]
+    #[requires(ub_checks::can_write(self.as_ptr()))]
     pub unsafe fn write_volatile(self, val: T)
     where
         T: Sized,
@@ -1075,6 +1226,7 @@ impl<T: ?Sized> NonNull<T> {
     #[cfg_attr(miri, track_caller)] // even without panics, this helps for Miri backtraces
     #[stable(feature = "non_null_convenience", since = "1.80.0")]
     #[rustc_const_unstable(feature = "const_ptr_write", issue = "86302")]
+    #[requires(ub_checks::can_write_unaligned(self.as_ptr()))]
     pub const unsafe fn write_unaligned(self, val: T)
     where
         T: Sized,
@@ -1091,6 +1243,7 @@ impl<T: ?Sized> NonNull<T> {
     /// [`ptr::replace`]: crate::ptr::replace()
     #[inline(always)]
     #[stable(feature = "non_null_convenience", since = "1.80.0")]
+    #[requires(ub_checks::can_dereference(self.pointer) && ub_checks::can_write(self.as_ptr()))]
     pub unsafe fn replace(self, src: T) -> T
     where
         T: Sized,
@@ -1109,6 +1262,12 @@ impl<T: ?Sized> NonNull<T> {
     #[inline(always)]
     #[stable(feature = "non_null_convenience", since = "1.80.0")]
     #[rustc_const_unstable(feature = "const_swap", issue = "83163")]
+    #[requires(
+        ub_checks::can_dereference(self.pointer)
+            && ub_checks::can_write(self.as_ptr())
+            && ub_checks::can_dereference(with.pointer)
+            && ub_checks::can_write(with.as_ptr())
+    )]
     pub const unsafe fn swap(self, with: NonNull<T>)
     where
         T: Sized,
@@ -1168,6 +1327,9 @@ impl<T: ?Sized> NonNull<T> {
     #[must_use]
     #[stable(feature = "non_null_convenience", since = "1.80.0")]
     #[rustc_const_unstable(feature = "const_align_offset", issue = "90962")]
+    #[ensures(|result| {
+        *result == usize::MAX || self.pointer.wrapping_add(*result).is_aligned_to(align)
+    })]
     pub const fn align_offset(self, align: usize) -> usize
     where
         T: Sized,
@@ -1292,6 +1454,7 @@ impl<T: ?Sized> NonNull<T> {
     #[must_use]
     #[stable(feature = "pointer_is_aligned", since = "1.79.0")]
     #[rustc_const_unstable(feature = "const_pointer_is_aligned", issue = "104203")]
+    #[ensures(|result| *result == (self.pointer.addr() % align_of::<T>() == 0))]
     pub const fn is_aligned(self) -> bool
     where
         T: Sized,
@@ -1407,6 +1570,7 @@ impl<T: ?Sized> NonNull<T> {
     #[must_use]
     #[unstable(feature = "pointer_is_aligned_to", issue = "96284")]
     #[rustc_const_unstable(feature = "const_pointer_is_aligned", issue = "104203")]
+    #[ensures(|result| *result == (self.pointer.addr() & (align - 1) == 0))]
     pub const fn is_aligned_to(self, align: usize) -> bool {
         self.pointer.is_aligned_to(align)
     }
@@ -1438,6 +1602,7 @@ impl<T> NonNull<[T]> {
     #[rustc_const_unstable(feature = "const_slice_from_raw_parts_mut", issue = "67456")]
     #[must_use]
     #[inline]
+    #[ensures(|result| result.len() == len && result.as_non_null_ptr() == data)]
     pub const fn slice_from_raw_parts(data: NonNull<T>, len: usize) -> Self {
         // SAFETY: `data` is a `NonNull` pointer which is necessarily non-null
         unsafe { Self::new_unchecked(super::slice_from_raw_parts_mut(data.as_ptr(), len)) }
@@ -1462,6 +1627,7 @@ impl<T> NonNull<[T]> {
     #[rustc_const_stable(feature = "const_slice_ptr_len_nonnull", since = "1.63.0")]
     #[must_use]
     #[inline]
+    #[ensures(|result| *result == ptr::metadata(self.as_ptr()))]
     pub const fn len(self) -> usize {
         self.as_ptr().len()
     }
@@ -1480,6 +1646,7 @@ impl<T> NonNull<[T]> {
     #[rustc_const_stable(feature = "const_slice_ptr_is_empty_nonnull", since = "1.79.0")]
     #[must_use]
     #[inline]
+    #[ensures(|result| *result == (self.len() == 0))]
     pub const fn is_empty(self) -> bool {
         self.len() == 0
     }
@@ -1499,6 +1666,7 @@ impl<T> NonNull<[T]> {
     #[must_use]
     #[unstable(feature = "slice_ptr_get", issue = "74265")]
     #[rustc_const_unstable(feature = "slice_ptr_get", issue = "74265")]
+    #[ensures(|result| result.pointer == self.pointer.cast::<T>())]
     pub const fn as_non_null_ptr(self) -> NonNull<T> {
         self.cast()
     }
@@ -1519,6 +1687,7 @@ impl<T> NonNull<[T]> {
     #[unstable(feature = "slice_ptr_get", issue = "74265")]
     #[rustc_const_unstable(feature = "slice_ptr_get", issue = "74265")]
     #[rustc_never_returns_null_ptr]
+    #[ensures(|result| *result == self.as_ptr().cast::<T>())]
     pub const fn as_mut_ptr(self) -> *mut T {
         self.as_non_null_ptr().as_ptr()
     }
@@ -1564,6 +1733,11 @@ impl<T> NonNull<[T]> {
     #[must_use]
     #[unstable(feature = "ptr_as_uninit", issue = "75402")]
     #[rustc_const_unstable(feature = "const_ptr_as_ref", issue = "91822")]
+    #[requires(
+        ub_checks::is_valid_allocation_size(size_of::<T>(), self.len())
+            && ub_checks::can_dereference(self.as_ptr() as *const [MaybeUninit<T>])
+    )]
+    #[ensures(|result| ptr::eq(*result, self.as_ptr() as *const [MaybeUninit<T>]))]
     pub const unsafe fn as_uninit_slice<'a>(self) -> &'a [MaybeUninit<T>] {
         // SAFETY: the caller must uphold the safety contract for `as_uninit_slice`.
         unsafe { slice::from_raw_parts(self.cast().as_ptr(), self.len()) }
@@ -1629,6 +1803,11 @@ impl<T> NonNull<[T]> {
     #[must_use]
     #[unstable(feature = "ptr_as_uninit", issue = "75402")]
     #[rustc_const_unstable(feature = "const_ptr_as_ref", issue = "91822")]
+    #[requires(
+        ub_checks::is_valid_allocation_size(size_of::<T>(), self.len())
+            && ub_checks::can_write(self.as_ptr() as *mut [MaybeUninit<T>])
+    )]
+    #[ensures(|result| ptr::eq(&**result, self.as_ptr() as *const [MaybeUninit<T>]))]
     pub const unsafe fn as_uninit_slice_mut<'a>(self) -> &'a mut [MaybeUninit<T>] {
         // SAFETY: the caller must uphold the safety contract for `as_uninit_slice_mut`.
         unsafe { slice::from_raw_parts_mut(self.cast().as_ptr(), self.len()) }
@@ -1657,6 +1836,11 @@ impl<T> NonNull<[T]> {
     /// ```
     #[unstable(feature = "slice_ptr_get", issue = "74265")]
     #[inline]
+    #[requires({
+        let data = self.as_mut_ptr();
+        index.is_in_bounds(self.len())
+            && ub_checks::same_allocation(data, data.wrapping_add(self.len()))
+    })]
     pub unsafe fn get_unchecked_mut<I>(self, index: I) -> NonNull<I::Output>
     where
         I: SliceIndex<[T]>,
@@ -1753,6 +1937,7 @@ impl<T: ?Sized> From<&mut T> for NonNull<T> {
     ///
     /// This conversion is safe and infallible since references cannot be null.
     #[inline]
+    #[ensures(|result| ptr::eq(result.as_ptr(), reference))]
     fn from(reference: &mut T) -> Self {
         // SAFETY: A mutable reference cannot be null.
         unsafe { NonNull { pointer: reference as *mut T } }
@@ -1765,8 +1950,489 @@ impl<T: ?Sized> From<&T> for NonNull<T> {
     ///
     /// This conversion is safe and infallible since references cannot be null.
     #[inline]
+    #[ensures(|result| ptr::eq(result.as_ptr(), reference))]
     fn from(reference: &T) -> Self {
         // SAFETY: A reference cannot be null.
         unsafe { NonNull { pointer: reference as *const T } }
     }
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+    use crate::ptr::arbitrary::{self, ARRAY_LEN};
+    use crate::ub_checks;
+
+    /// Returns a pointer to an arbitrary element of the array `base` points to, or one past its
+    /// end.
+    fn any_element_ptr<T>(base: NonNull<[T; ARRAY_LEN]>) -> NonNull<T> {
+        NonNull::new(arbitrary::any_element_ptr(base.as_ptr())).unwrap()
+    }
+
+    /// Returns a pointer to an arbitrary byte of the array `base` points to, or one past its end,
+    /// which is not necessarily aligned.
+    fn any_byte_ptr<T>(base: NonNull<[T; ARRAY_LEN]>) -> NonNull<T> {
+        NonNull::new(arbitrary::any_byte_ptr(base.as_ptr())).unwrap()
+    }
+
+    /// Returns a pointer to an arbitrary subslice of the array `base` points to.
+    fn any_slice_ptr<T>(base: NonNull<[T; ARRAY_LEN]>) -> NonNull<[T]> {
+        let start = arbitrary::any_index();
+        let len = ub_checks::any_in(0..=ARRAY_LEN - start);
+        let data = NonNull::new(base.cast::<T>().as_ptr().wrapping_add(start)).unwrap();
+        NonNull::slice_from_raw_parts(data, len)
+    }
+
+    macro_rules! generate_non_null_harnesses {
+        ($module:ident, $ty:ty) => {
+            generate_non_null_harnesses!($module, $ty, {
+                // pub const unsafe fn offset_from(self, origin: NonNull<T>) -> isize
+                #[safety::proof_for_contract(NonNull::<$ty>::offset_from)]
+                fn check_offset_from() {
+                    let mut array: [$ty; ARRAY_LEN] = ub_checks::any();
+                    let base = NonNull::from(&mut array);
+                    let (ptr, origin) = (any_element_ptr(base), any_element_ptr(base));
+                    let _ = unsafe { ptr.offset_from(origin) };
+                }
+
+                // pub const unsafe fn sub_ptr(self, subtracted: NonNull<T>) -> usize
+                #[safety::proof_for_contract(NonNull::<$ty>::sub_ptr)]
+                fn check_sub_ptr() {
+                    let mut array: [$ty; ARRAY_LEN] = ub_checks::any();
+                    let base = NonNull::from(&mut array);
+                    let (ptr, subtracted) = (any_element_ptr(base), any_element_ptr(base));
+                    let _ = unsafe { ptr.sub_ptr(subtracted) };
+                }
+            });
+        };
+        ($module:ident, $ty:ty, { $($extra:item)* }) => {
+            mod $module {
+                use super::*;
+
+                // pub const unsafe fn add(self, count: usize) -> Self
+                #[safety::proof_for_contract(NonNull::<$ty>::add)]
+                fn check_add() {
+                    let mut array: [$ty; ARRAY_LEN] = ub_checks::any();
+                    let ptr = any_element_ptr(NonNull::from(&mut array));
+                    let _ = unsafe { ptr.add(ub_checks::any()) };
+                }
+
+                // pub const unsafe fn sub(self, count: usize) -> Self
+                #[safety::proof_for_contract(NonNull::<$ty>::sub)]
+                fn check_sub() {
+                    let mut array: [$ty; ARRAY_LEN] = ub_checks::any();
+                    let ptr = any_element_ptr(NonNull::from(&mut array));
+                    let _ = unsafe { ptr.sub(ub_checks::any()) };
+                }
+
+                // pub const unsafe fn offset(self, count: isize) -> Self
+                #[safety::proof_for_contract(NonNull::<$ty>::offset)]
+                fn check_offset() {
+                    let mut array: [$ty; ARRAY_LEN] = ub_checks::any();
+                    let ptr = any_element_ptr(NonNull::from(&mut array));
+                    let _ = unsafe { ptr.offset(ub_checks::any()) };
+                }
+
+                // pub const unsafe fn byte_add(self, count: usize) -> Self
+                #[safety::proof_for_contract(NonNull::<$ty>::byte_add)]
+                fn check_byte_add() {
+                    let mut array: [$ty; ARRAY_LEN] = ub_checks::any();
+                    let ptr = any_byte_ptr(NonNull::from(&mut array));
+                    let _ = unsafe { ptr.byte_add(ub_checks::any()) };
+                }
+
+                // pub const unsafe fn byte_sub(self, count: usize) -> Self
+                #[safety::proof_for_contract(NonNull::<$ty>::byte_sub)]
+                fn check_byte_sub() {
+                    let mut array: [$ty; ARRAY_LEN] = ub_checks::any();
+                    let ptr = any_byte_ptr(NonNull::from(&mut array));
+                    let _ = unsafe { ptr.byte_sub(ub_checks::any()) };
+                }
+
+                // pub const unsafe fn byte_offset(self, count: isize) -> Self
+                #[safety::proof_for_contract(NonNull::<$ty>::byte_offset)]
+                fn check_byte_offset() {
+                    let mut array: [$ty; ARRAY_LEN] = ub_checks::any();
+                    let ptr = any_byte_ptr(NonNull::from(&mut array));
+                    let _ = unsafe { ptr.byte_offset(ub_checks::any()) };
+                }
+
+                // pub const unsafe fn byte_offset_from<U: ?Sized>(
+                //     self,
+                //     origin: NonNull<U>,
+                // ) -> isize
+                #[safety::proof_for_contract(NonNull::<$ty>::byte_offset_from)]
+                fn check_byte_offset_from() {
+                    let mut array: [$ty; ARRAY_LEN] = ub_checks::any();
+                    let base = NonNull::from(&mut array);
+                    let (ptr, origin) = (any_byte_ptr(base), any_byte_ptr(base).cast::<u8>());
+                    let _ = unsafe { ptr.byte_offset_from(origin) };
+                }
+
+                // pub const unsafe fn as_ref<'a>(&self) -> &'a T
+                #[safety::proof_for_contract(NonNull::<$ty>::as_ref)]
+                fn check_as_ref() {
+                    let mut array: [$ty; ARRAY_LEN] = ub_checks::any();
+                    let ptr = any_byte_ptr(NonNull::from(&mut array));
+                    let _ = unsafe { ptr.as_ref() };
+                }
+
+                // pub const unsafe fn as_mut<'a>(&mut self) -> &'a mut T
+                #[safety::proof_for_contract(NonNull::<$ty>::as_mut)]
+                fn check_as_mut() {
+                    let mut array: [$ty; ARRAY_LEN] = ub_checks::any();
+                    let mut ptr = any_byte_ptr(NonNull::from(&mut array));
+                    let _ = unsafe { ptr.as_mut() };
+                }
+
+                // pub const unsafe fn as_uninit_ref<'a>(self) -> &'a MaybeUninit<T>
+                #[safety::proof_for_contract(NonNull::<$ty>::as_uninit_ref)]
+                fn check_as_uninit_ref() {
+                    let mut array = [MaybeUninit::<$ty>::uninit(); ARRAY_LEN];
+                    let ptr = any_byte_ptr(NonNull::from(&mut array)).cast::<$ty>();
+                    let _ = unsafe { ptr.as_uninit_ref() };
+                }
+
+                // pub const unsafe fn as_uninit_mut<'a>(self) -> &'a mut MaybeUninit<T>
+                #[safety::proof_for_contract(NonNull::<$ty>::as_uninit_mut)]
+                fn check_as_uninit_mut() {
+                    let mut array = [MaybeUninit::<$ty>::uninit(); ARRAY_LEN];
+                    let ptr = any_byte_ptr(NonNull::from(&mut array)).cast::<$ty>();
+                    let _ = unsafe { ptr.as_uninit_mut() };
+                }
+
+                // pub const unsafe fn read(self) -> T
+                #[safety::proof_for_contract(NonNull::<$ty>::read)]
+                fn check_read() {
+                    let mut array: [$ty; ARRAY_LEN] = ub_checks::any();
+                    let ptr = any_byte_ptr(NonNull::from(&mut array));
+                    let _ = unsafe { ptr.read() };
+                }
+
+                // pub unsafe fn read_volatile(self) -> T
+                #[safety::proof_for_contract(NonNull::<$ty>::read_volatile)]
+                fn check_read_volatile() {
+                    let mut array: [$ty; ARRAY_LEN] = ub_checks::any();
+                    let ptr = any_byte_ptr(NonNull::from(&mut array));
+                    let _ = unsafe { ptr.read_volatile() };
+                }
+
+                // pub const unsafe fn read_unaligned(self) -> T
+                #[safety::proof_for_contract(NonNull::<$ty>::read_unaligned)]
+                fn check_read_unaligned() {
+                    let mut array: [$ty; ARRAY_LEN] = ub_checks::any();
+                    let ptr = any_byte_ptr(NonNull::from(&mut array));
+                    let _ = unsafe { ptr.read_unaligned() };
+                }
+
+                // pub const unsafe fn write(self, val: T)
+                #[safety::proof_for_contract(NonNull::<$ty>::write)]
+                fn check_write() {
+                    let mut array: [$ty; ARRAY_LEN] = ub_checks::any();
+                    let ptr = any_byte_ptr(NonNull::from(&mut array));
+                    unsafe { ptr.write(ub_checks::any()) };
+                }
+
+                // pub unsafe fn write_volatile(self, val: T)
+                #[safety::proof_for_contract(NonNull::<$ty>::write_volatile)]
+                fn check_write_volatile() {
+                    let mut array: [$ty; ARRAY_LEN] = ub_checks::any();
+                    let ptr = any_byte_ptr(NonNull::from(&mut array));
+                    unsafe { ptr.write_volatile(ub_checks::any()) };
+                }
+
+                // pub const unsafe fn write_unaligned(self, val: T)
+                #[safety::proof_for_contract(NonNull::<$ty>::write_unaligned)]
+                fn check_write_unaligned() {
+                    let mut array: [$ty; ARRAY_LEN] = ub_checks::any();
+                    let ptr = any_byte_ptr(NonNull::from(&mut array));
+                    unsafe { ptr.write_unaligned(ub_checks::any()) };
+                }
+
+                // pub const unsafe fn write_bytes(self, val: u8, count: usize)
+                #[safety::proof_for_contract(NonNull::<$ty>::write_bytes)]
+                fn check_write_bytes() {
+                    let mut array: [$ty; ARRAY_LEN] = ub_checks::any();
+                    let ptr = any_element_ptr(NonNull::from(&mut array));
+                    unsafe { ptr.write_bytes(ub_checks::any(), ub_checks::any()) };
+                }
+
+                // pub unsafe fn replace(self, src: T) -> T
+                #[safety::proof_for_contract(NonNull::<$ty>::replace)]
+                fn check_replace() {
+                    let mut array: [$ty; ARRAY_LEN] = ub_checks::any();
+                    let ptr = any_byte_ptr(NonNull::from(&mut array));
+                    let _ = unsafe { ptr.replace(ub_checks::any()) };
+                }
+
+                // pub const unsafe fn swap(self, with: NonNull<T>)
+                #[safety::proof_for_contract(NonNull::<$ty>::swap)]
+                fn check_swap() {
+                    let mut array: [$ty; ARRAY_LEN] = ub_checks::any();
+                    let base = NonNull::from(&mut array);
+                    unsafe { any_byte_ptr(base).swap(any_byte_ptr(base)) };
+                }
+
+                // pub const unsafe fn copy_to(self, dest: NonNull<T>, count: usize)
+                #[safety::proof_for_contract(NonNull::<$ty>::copy_to)]
+                fn check_copy_to() {
+                    let mut array: [$ty; ARRAY_LEN] = ub_checks::any();
+                    let base = NonNull::from(&mut array);
+                    let (src, dest) = (any_element_ptr(base), any_element_ptr(base));
+                    unsafe { src.copy_to(dest, ub_checks::any()) };
+                }
+
+                // pub const unsafe fn copy_to_nonoverlapping(self, dest: NonNull<T>, count: usize)
+                #[safety::proof_for_contract(NonNull::<$ty>::copy_to_nonoverlapping)]
+                fn check_copy_to_nonoverlapping() {
+                    let mut array: [$ty; ARRAY_LEN] = ub_checks::any();
+                    let base = NonNull::from(&mut array);
+                    let (src, dest) = (any_element_ptr(base), any_element_ptr(base));
+                    unsafe { src.copy_to_nonoverlapping(dest, ub_checks::any()) };
+                }
+
+                // pub const unsafe fn copy_from(self, src: NonNull<T>, count: usize)
+                #[safety::proof_for_contract(NonNull::<$ty>::copy_from)]
+                fn check_copy_from() {
+                    let mut array: [$ty; ARRAY_LEN] = ub_checks::any();
+                    let base = NonNull::from(&mut array);
+                    let (dest, src) = (any_element_ptr(base), any_element_ptr(base));
+                    unsafe { dest.copy_from(src, ub_checks::any()) };
+                }
+
+                // pub const unsafe fn copy_from_nonoverlapping(self, src: NonNull<T>, count: usize)
+                #[safety::proof_for_contract(NonNull::<$ty>::copy_from_nonoverlapping)]
+                fn check_copy_from_nonoverlapping() {
+                    let mut array: [$ty; ARRAY_LEN] = ub_checks::any();
+                    let base = NonNull::from(&mut array);
+                    let (dest, src) = (any_element_ptr(base), any_element_ptr(base));
+                    unsafe { dest.copy_from_nonoverlapping(src, ub_checks::any()) };
+                }
+
+                // pub unsafe fn drop_in_place(self)
+                #[safety::proof_for_contract(NonNull::<$ty>::drop_in_place)]
+                fn check_drop_in_place() {
+                    let mut array: [$ty; ARRAY_LEN] = ub_checks::any();
+                    let ptr = any_byte_ptr(NonNull::from(&mut array));
+                    unsafe { ptr.drop_in_place() };
+                }
+
+                // pub const fn align_offset(self, align: usize) -> usize
+                #[safety::proof_for_contract(NonNull::<$ty>::align_offset)]
+                fn check_align_offset() {
+                    let mut array: [$ty; ARRAY_LEN] = ub_checks::any();
+                    let ptr = any_byte_ptr(NonNull::from(&mut array));
+                    let _ = ptr.align_offset(1 << ub_checks::any_in(0..8u32));
+                }
+
+                // pub const fn is_aligned(self) -> bool
+                #[safety::proof_for_contract(NonNull::<$ty>::is_aligned)]
+                fn check_is_aligned() {
+                    let mut array: [$ty; ARRAY_LEN] = ub_checks::any();
+                    let _ = any_byte_ptr(NonNull::from(&mut array)).is_aligned();
+                }
+
+                $($extra)*
+            }
+        };
+    }
+
+    // `offset_from` and `sub_ptr` panic for zero-sized types, so they have no harness for `()`.
+    generate_non_null_harnesses!(check_unit, (), {});
+    generate_non_null_harnesses!(check_u8, u8);
+    generate_non_null_harnesses!(check_i32, i32);
+    generate_non_null_harnesses!(check_u128, u128);
+    generate_non_null_harnesses!(check_u16_array, [u16; 3]);
+
+    // fn from(reference: &mut T) -> Self
+    #[safety::proof_for_contract(<NonNull<[i32]> as From<&mut [i32]>>::from)]
+    fn check_from_mut_ref() {
+        let mut array: [i32; ARRAY_LEN] = ub_checks::any();
+        let len = ub_checks::any_in(0..=ARRAY_LEN);
+        let _ = NonNull::from(&mut array[..len]);
+    }
+
+    // fn from(reference: &T) -> Self
+    #[safety::proof_for_contract(<NonNull<[i32]> as From<&[i32]>>::from)]
+    fn check_from_ref() {
+        let array: [i32; ARRAY_LEN] = ub_checks::any();
+        let len = ub_checks::any_in(0..=ARRAY_LEN);
+        let _ = NonNull::from(&array[..len]);
+    }
+
+    // pub const fn dangling() -> Self
+    #[safety::proof_for_contract(NonNull::<u128>::dangling)]
+    fn check_dangling() {
+        let _ = NonNull::<u128>::dangling();
+    }
+
+    // pub const unsafe fn new_unchecked(ptr: *mut T) -> Self
+    #[safety::proof_for_contract(NonNull::<i32>::new_unchecked)]
+    fn check_new_unchecked() {
+        let ptr = ub_checks::any::<usize>() as *mut i32;
+        let _ = unsafe { NonNull::new_unchecked(ptr) };
+    }
+
+    // pub const fn new(ptr: *mut T) -> Option<Self>
+    #[safety::proof_for_contract(NonNull::<i32>::new)]
+    fn check_new() {
+        let ptr = ub_checks::any::<usize>() as *mut i32;
+        let _ = NonNull::new(ptr);
+    }
+
+    // pub const fn from_raw_parts(
+    //     data_pointer: NonNull<()>,
+    //     metadata: <T as super::Pointee>::Metadata,
+    // ) -> NonNull<T>
+    #[safety::proof_for_contract(NonNull::<[i32]>::from_raw_parts)]
+    fn check_from_raw_parts() {
+        let mut array: [i32; ARRAY_LEN] = ub_checks::any();
+        let data = any_element_ptr(NonNull::from(&mut array)).cast::<()>();
+        let _ = NonNull::<[i32]>::from_raw_parts(data, ub_checks::any());
+    }
+
+    // pub const fn to_raw_parts(self) -> (NonNull<()>, <T as super::Pointee>::Metadata)
+    #[safety::proof_for_contract(NonNull::<[i32]>::to_raw_parts)]
+    fn check_to_raw_parts() {
+        let mut array: [i32; ARRAY_LEN] = ub_checks::any();
+        let _ = any_slice_ptr(NonNull::from(&mut array)).to_raw_parts();
+    }
+
+    // pub fn addr(self) -> NonZero<usize>
+    #[safety::proof_for_contract(NonNull::<i32>::addr)]
+    fn check_addr() {
+        let mut array: [i32; ARRAY_LEN] = ub_checks::any();
+        let _ = any_byte_ptr(NonNull::from(&mut array)).addr();
+    }
+
+    // pub fn with_addr(self, addr: NonZero<usize>) -> Self
+    #[safety::proof_for_contract(NonNull::<[i32]>::with_addr)]
+    fn check_with_addr() {
+        let mut array: [i32; ARRAY_LEN] = ub_checks::any();
+        let addr = NonZero::new(ub_checks::any_where(|addr: &usize| *addr != 0)).unwrap();
+        let _ = any_slice_ptr(NonNull::from(&mut array)).with_addr(addr);
+    }
+
+    // pub fn map_addr(self, f: impl FnOnce(NonZero<usize>) -> NonZero<usize>) -> Self
+    #[safety::proof_for_contract(NonNull::<[i32]>::map_addr)]
+    fn check_map_addr() {
+        let mut array: [i32; ARRAY_LEN] = ub_checks::any();
+        let offset: usize = ub_checks::any();
+        let ptr = any_slice_ptr(NonNull::from(&mut array));
+        let _ = ptr.map_addr(|addr| addr.saturating_add(offset));
+    }
+
+    // pub const fn as_ptr(self) -> *mut T
+    #[safety::proof_for_contract(NonNull::<[i32]>::as_ptr)]
+    fn check_as_ptr() {
+        let mut array: [i32; ARRAY_LEN] = ub_checks::any();
+        let _ = any_slice_ptr(NonNull::from(&mut array)).as_ptr();
+    }
+
+    // pub const fn cast<U>(self) -> NonNull<U>
+    #[safety::proof_for_contract(NonNull::<[i32]>::cast)]
+    fn check_cast() {
+        let mut array: [i32; ARRAY_LEN] = ub_checks::any();
+        let _ = any_slice_ptr(NonNull::from(&mut array)).cast::<u8>();
+    }
+
+    // pub const fn is_aligned_to(self, align: usize) -> bool
+    #[safety::proof_for_contract(NonNull::<[i32]>::is_aligned_to)]
+    fn check_is_aligned_to() {
+        let mut array: [i32; ARRAY_LEN] = ub_checks::any();
+        let ptr = any_slice_ptr(NonNull::from(&mut array));
+        let _ = ptr.is_aligned_to(1 << ub_checks::any_in(0..8u32));
+    }
+
+    // pub const unsafe fn as_ref<'a>(&self) -> &'a T
+    #[safety::proof_for_contract(NonNull::<[i32]>::as_ref)]
+    fn check_slice_as_ref() {
+        let mut array: [i32; ARRAY_LEN] = ub_checks::any();
+        let _ = unsafe { any_slice_ptr(NonNull::from(&mut array)).as_ref() };
+    }
+
+    // pub const unsafe fn as_mut<'a>(&mut self) -> &'a mut T
+    #[safety::proof_for_contract(NonNull::<[i32]>::as_mut)]
+    fn check_slice_as_mut() {
+        let mut array: [i32; ARRAY_LEN] = ub_checks::any();
+        let _ = unsafe { any_slice_ptr(NonNull::from(&mut array)).as_mut() };
+    }
+
+    // pub unsafe fn drop_in_place(self)
+    #[safety::proof_for_contract(NonNull::<[i32]>::drop_in_place)]
+    fn check_slice_drop_in_place() {
+        let mut array: [i32; ARRAY_LEN] = ub_checks::any();
+        unsafe { any_slice_ptr(NonNull::from(&mut array)).drop_in_place() };
+    }
+
+    // pub const fn slice_from_raw_parts(data: NonNull<T>, len: usize) -> Self
+    #[safety::proof_for_contract(NonNull::<[i32]>::slice_from_raw_parts)]
+    fn check_slice_from_raw_parts() {
+        let mut array: [i32; ARRAY_LEN] = ub_checks::any();
+        let data = any_element_ptr(NonNull::from(&mut array));
+        let _ = NonNull::slice_from_raw_parts(data, ub_checks::any());
+    }
+
+    // pub const fn len(self) -> usize
+    #[safety::proof_for_contract(NonNull::<[i32]>::len)]
+    fn check_len() {
+        let mut array: [i32; ARRAY_LEN] = ub_checks::any();
+        let _ = any_slice_ptr(NonNull::from(&mut array)).len();
+    }
+
+    // pub const fn is_empty(self) -> bool
+    #[safety::proof_for_contract(NonNull::<[i32]>::is_empty)]
+    fn check_is_empty() {
+        let mut array: [i32; ARRAY_LEN] = ub_checks::any();
+        let _ = any_slice_ptr(NonNull::from(&mut array)).is_empty();
+    }
+
+    // pub const fn as_non_null_ptr(self) -> NonNull<T>
+    #[safety::proof_for_contract(NonNull::<[i32]>::as_non_null_ptr)]
+    fn check_as_non_null_ptr() {
+        let mut array: [i32; ARRAY_LEN] = ub_checks::any();
+        let _ = any_slice_ptr(NonNull::from(&mut array)).as_non_null_ptr();
+    }
+
+    // pub const fn as_mut_ptr(self) -> *mut T
+    #[safety::proof_for_contract(NonNull::<[i32]>::as_mut_ptr)]
+    fn check_as_mut_ptr() {
+        let mut array: [i32; ARRAY_LEN] = ub_checks::any();
+        let _ = any_slice_ptr(NonNull::from(&mut array)).as_mut_ptr();
+    }
+
+    // pub const unsafe fn as_uninit_slice<'a>(self) -> &'a [MaybeUninit<T>]
+    #[safety::proof_for_contract(NonNull::<[i32]>::as_uninit_slice)]
+    fn check_as_uninit_slice() {
+        let mut array = [MaybeUninit::<i32>::uninit(); ARRAY_LEN];
+        let data = any_element_ptr(NonNull::from(&mut array)).cast::<i32>();
+        let ptr = NonNull::slice_from_raw_parts(data, ub_checks::any());
+        let _ = unsafe { ptr.as_uninit_slice() };
+    }
+
+    // pub const unsafe fn as_uninit_slice_mut<'a>(self) -> &'a mut [MaybeUninit<T>]
+    #[safety::proof_for_contract(NonNull::<[i32]>::as_uninit_slice_mut)]
+    fn check_as_uninit_slice_mut() {
+        let mut array = [MaybeUninit::<i32>::uninit(); ARRAY_LEN];
+        let data = any_element_ptr(NonNull::from(&mut array)).cast::<i32>();
+        let ptr = NonNull::slice_from_raw_parts(data, ub_checks::any());
+        let _ = unsafe { ptr.as_uninit_slice_mut() };
+    }
+
+    // pub unsafe fn get_unchecked_mut<I>(self, index: I) -> NonNull<I::Output>
+    #[safety::proof_for_contract(NonNull::<[i32]>::get_unchecked_mut)]
+    fn check_get_unchecked_mut() {
+        let mut array: [i32; ARRAY_LEN] = ub_checks::any();
+        let ptr = any_slice_ptr(NonNull::from(&mut array));
+        let _ = unsafe { ptr.get_unchecked_mut(ub_checks::any::<usize>()) };
+    }
+
+    #[safety::proof_for_contract(NonNull::<[i32]>::get_unchecked_mut)]
+    fn check_get_unchecked_mut_range() {
+        let mut array: [i32; ARRAY_LEN] = ub_checks::any();
+        let ptr = any_slice_ptr(NonNull::from(&mut array));
+        let range = ub_checks::any::<usize>()..ub_checks::any();
+        let _ = unsafe { ptr.get_unchecked_mut(range) };
+    }
+}
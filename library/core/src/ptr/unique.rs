@@ -4,6 +4,7 @@ use crate::marker::{PhantomData, Unsize};
 use crate::ops::{CoerceUnsized, DispatchFromDyn};
 use crate::pin::PinCoerceUnsized;
 use crate::ptr::NonNull;
+use crate::ub_checks::{self, Invariant};
 
 #[cfg(kani)]
 use crate::kani;
@@ -38,6 +39,7 @@ use crate::kani;
 #[repr(transparent)]
 // Lang item used experimentally by Miri to define the semantics of `Unique`.
 #[lang = "ptr_unique"]
+#[safety::invariant(self.pointer.is_safe())]
 pub struct Unique<T: ?Sized> {
     pointer: NonNull<T>,
     // NOTE: this marker has no consequences for variance, but is necessary
@@ -93,7 +95,10 @@ impl<T: ?Sized> Unique<T> {
     #[ensures(|result| result.as_ptr() == ptr)]
     pub const unsafe fn new_unchecked(ptr: *mut T) -> Self {
         // SAFETY: the caller must guarantee that `ptr` is non-null.
-        unsafe { Unique { pointer: NonNull::new_unchecked(ptr), _marker: PhantomData } }
+        let unique =
+            unsafe { Unique { pointer: NonNull::new_unchecked(ptr), _marker: PhantomData } };
+        ub_checks::assert_invariant(&unique);
+        unique
     }
 
     /// Creates a new `Unique` if `ptr` is non-null.
@@ -102,7 +107,9 @@ impl<T: ?Sized> Unique<T> {
     #[ensures(|result| result.is_none() || result.unwrap().as_ptr() == ptr)]
     pub const fn new(ptr: *mut T) -> Option<Self> {
         if let Some(pointer) = NonNull::new(ptr) {
-            Some(Unique { pointer, _marker: PhantomData })
+            let unique = Unique { pointer, _marker: PhantomData };
+            ub_checks::assert_invariant(&unique);
+            Some(unique)
         } else {
             None
         }
@@ -212,85 +219,13 @@ impl<T: ?Sized> From<NonNull<T>> for Unique<T> {
     /// This conversion is infallible since `NonNull` cannot be null.
     #[inline]
     fn from(pointer: NonNull<T>) -> Self {
-        Unique { pointer, _marker: PhantomData }
+        let unique = Unique { pointer, _marker: PhantomData };
+        ub_checks::assert_invariant(&unique);
+        unique
     }
 }
 
+// The harnesses only use the public API, so `coretests` also runs them as unit tests.
 #[cfg(kani)]
 #[unstable(feature="kani", issue="none")]
-mod verify {
-    use super::*;
-
-    // pub const unsafe fn new_unchecked(ptr: *mut T) -> Self
-    #[kani::proof_for_contract(Unique::new_unchecked)]
-    pub fn check_new_unchecked() {
-        let mut x : i32 = kani::any();
-        let xptr = &mut x;
-        unsafe {
-            let _ = Unique::new_unchecked(xptr as *mut i32);
-        }
-    }
-
-    // pub const fn new(ptr: *mut T) -> Option<Self>
-    #[kani::proof_for_contract(Unique::new)]
-    pub fn check_new() {
-        let mut x : i32 = kani::any();
-        let xptr = &mut x;
-        let _ = Unique::new(xptr as *mut i32);
-    }
-
-    // pub const fn as_ptr(self) -> *mut T
-    #[kani::proof_for_contract(Unique::as_ptr)]
-    pub fn check_as_ptr() {
-        let mut x : i32 = kani::any();
-        let xptr = &mut x;
-        unsafe {
-            let unique = Unique::new_unchecked(xptr as *mut i32);
-            assert_eq!(unique.as_ptr(), xptr as *mut i32);
-        }
-    }
-
-    // pub const fn as_non_null_ptr(self) -> NonNull<T>
-    #[kani::proof_for_contract(Unique::as_non_null_ptr)]
-    pub fn check_as_non_null_ptr() {
-        let mut x : i32 = kani::any();
-        let xptr = &mut x;
-        unsafe {
-            let unique = Unique::new_unchecked(xptr as *mut i32);
-            let _ = unique.as_non_null_ptr();
-        }
-    }
-
-    // pub const unsafe fn as_ref(&self) -> &T
-    #[kani::proof]
-    pub fn check_as_ref() {
-        let mut x : i32 = kani::any();
-        let xptr = &mut x;
-        unsafe {
-            let unique = Unique::new_unchecked(xptr as *mut i32);
-            assert_eq!(*unique.as_ref(), x);
-        }
-    }
-
-    // pub const unsafe fn as_mut(&mut self) -> &mut T
-    #[kani::proof]
-    pub fn check_as_mut() {
-        let mut x : i32 = kani::any();
-        let xptr = &mut x;
-        unsafe {
-            let mut unique = Unique::new_unchecked(xptr as *mut i32);
-            assert_eq!(*unique.as_mut(), x);
-        }
-    }
-
-    // pub const fn cast<U>(self) -> Unique<U>
-    #[kani::proof_for_contract(Unique::cast<U>)]
-    pub fn check_cast<U>() {
-        let mut x : i32 = kani::any();
-        let xptr = &mut x;
-        unsafe {
-            let unique = Unique::new_unchecked(xptr as *mut i32);
-            assert_eq!(*unique.cast::<u32>().as_ref(), x as u32);
-        }
-    }
-}
+mod verify;
@@ -0,0 +1,77 @@
+//! Harnesses for `Unique`.
+
+use core::ptr::Unique;
+use core::ub_checks;
+
+// pub const unsafe fn new_unchecked(ptr: *mut T) -> Self
+#[safety::proof_for_contract(Unique::new_unchecked)]
+pub fn check_new_unchecked() {
+    let mut x : i32 = ub_checks::any();
+    let xptr = &mut x;
+    unsafe {
+        let _ = Unique::new_unchecked(xptr as *mut i32);
+    }
+}
+
+// pub const fn new(ptr: *mut T) -> Option<Self>
+#[safety::proof_for_contract(Unique::new)]
+pub fn check_new() {
+    let mut x : i32 = ub_checks::any();
+    let xptr = &mut x;
+    let _ = Unique::new(xptr as *mut i32);
+}
+
+// pub const fn as_ptr(self) -> *mut T
+#[safety::proof_for_contract(Unique::as_ptr)]
+pub fn check_as_ptr() {
+    let mut x : i32 = ub_checks::any();
+    let xptr = &mut x;
+    unsafe {
+        let unique = Unique::new_unchecked(xptr as *mut i32);
+        assert_eq!(unique.as_ptr(), xptr as *mut i32);
+    }
+}
+
+// pub const fn as_non_null_ptr(self) -> NonNull<T>
+#[safety::proof_for_contract(Unique::as_non_null_ptr)]
+pub fn check_as_non_null_ptr() {
+    let mut x : i32 = ub_checks::any();
+    let xptr = &mut x;
+    unsafe {
+        let unique = Unique::new_unchecked(xptr as *mut i32);
+        let _ = unique.as_non_null_ptr();
+    }
+}
+
+// pub const unsafe fn as_ref(&self) -> &T
+#[safety::harness]
+pub fn check_as_ref() {
+    let mut x : i32 = ub_checks::any();
+    let xptr = &mut x;
+    unsafe {
+        let unique = Unique::new_unchecked(xptr as *mut i32);
+        assert_eq!(*unique.as_ref(), x);
+    }
+}
+
+// pub const unsafe fn as_mut(&mut self) -> &mut T
+#[safety::harness]
+pub fn check_as_mut() {
+    let mut x : i32 = ub_checks::any();
+    let xptr = &mut x;
+    unsafe {
+        let mut unique = Unique::new_unchecked(xptr as *mut i32);
+        assert_eq!(*unique.as_mut(), x);
+    }
+}
+
+// pub const fn cast<U>(self) -> Unique<U>
+#[safety::proof_for_contract(Unique::cast)]
+pub fn check_cast() {
+    let mut x : i32 = ub_checks::any();
+    let xptr = &mut x;
+    unsafe {
+        let unique = Unique::new_unchecked(xptr as *mut i32);
+        assert_eq!(*unique.cast::<u32>().as_ref(), x as u32);
+    }
+}
@@ -420,3 +420,23 @@ const fn is_ascii(s: &[u8]) -> bool {
 
     !contains_nonascii(last_word)
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+    use crate::ub_checks;
+
+    // const fn is_ascii(s: &[u8]) -> bool
+    #[safety::harness]
+    #[safety::stub_verified(<*const u8>::add)]
+    #[safety::stub_verified(<*const usize>::add)]
+    fn check_is_ascii() {
+        // Long enough to go through the word-at-a-time loop.
+        const LEN: usize = 3 * mem::size_of::<usize>();
+        let bytes: [u8; LEN] = ub_checks::any();
+        let len = ub_checks::any_in(0..=LEN);
+        let slice = &bytes[..len];
+        assert_eq!(is_ascii(slice), slice.iter().all(|byte| byte.is_ascii()));
+    }
+}
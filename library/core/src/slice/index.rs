@@ -147,36 +147,90 @@ const unsafe fn get_offset_len_mut_noubcheck<T>(
 }
 
 mod private_slice_index {
-    use super::{ops, range};
+    use super::{into_range, ops, range};
 
     #[stable(feature = "slice_get_slice", since = "1.28.0")]
-    pub trait Sealed {}
+    pub trait Sealed {
+        /// Returns whether this index is in bounds of a slice of length `len`, which is what
+        /// `SliceIndex::get_unchecked(_mut)` requires of it.
+        #[unstable(feature = "slice_index_methods", issue = "none")]
+        fn is_in_bounds(&self, len: usize) -> bool;
+    }
 
     #[stable(feature = "slice_get_slice", since = "1.28.0")]
-    impl Sealed for usize {}
+    impl Sealed for usize {
+        fn is_in_bounds(&self, len: usize) -> bool {
+            *self < len
+        }
+    }
     #[stable(feature = "slice_get_slice", since = "1.28.0")]
-    impl Sealed for ops::Range<usize> {}
+    impl Sealed for ops::Range<usize> {
+        fn is_in_bounds(&self, len: usize) -> bool {
+            self.start <= self.end && self.end <= len
+        }
+    }
     #[stable(feature = "slice_get_slice", since = "1.28.0")]
-    impl Sealed for ops::RangeTo<usize> {}
+    impl Sealed for ops::RangeTo<usize> {
+        fn is_in_bounds(&self, len: usize) -> bool {
+            self.end <= len
+        }
+    }
     #[stable(feature = "slice_get_slice", since = "1.28.0")]
-    impl Sealed for ops::RangeFrom<usize> {}
+    impl Sealed for ops::RangeFrom<usize> {
+        fn is_in_bounds(&self, len: usize) -> bool {
+            self.start <= len
+        }
+    }
     #[stable(feature = "slice_get_slice", since = "1.28.0")]
-    impl Sealed for ops::RangeFull {}
+    impl Sealed for ops::RangeFull {
+        fn is_in_bounds(&self, _len: usize) -> bool {
+            true
+        }
+    }
     #[stable(feature = "slice_get_slice", since = "1.28.0")]
-    impl Sealed for ops::RangeInclusive<usize> {}
+    impl Sealed for ops::RangeInclusive<usize> {
+        fn is_in_bounds(&self, len: usize) -> bool {
+            // `end < len` rules out the overflow of `end + 1` in `into_slice_range`.
+            *self.end() < len && self.clone().into_slice_range().is_in_bounds(len)
+        }
+    }
     #[stable(feature = "slice_get_slice", since = "1.28.0")]
-    impl Sealed for ops::RangeToInclusive<usize> {}
+    impl Sealed for ops::RangeToInclusive<usize> {
+        fn is_in_bounds(&self, len: usize) -> bool {
+            self.end < len
+        }
+    }
     #[stable(feature = "slice_index_with_ops_bound_pair", since = "1.53.0")]
-    impl Sealed for (ops::Bound<usize>, ops::Bound<usize>) {}
+    impl Sealed for (ops::Bound<usize>, ops::Bound<usize>) {
+        fn is_in_bounds(&self, len: usize) -> bool {
+            into_range(len, *self).is_some_and(|range| range.is_in_bounds(len))
+        }
+    }
 
     #[unstable(feature = "new_range_api", issue = "125687")]
-    impl Sealed for range::Range<usize> {}
+    impl Sealed for range::Range<usize> {
+        fn is_in_bounds(&self, len: usize) -> bool {
+            ops::Range::from(*self).is_in_bounds(len)
+        }
+    }
     #[unstable(feature = "new_range_api", issue = "125687")]
-    impl Sealed for range::RangeInclusive<usize> {}
+    impl Sealed for range::RangeInclusive<usize> {
+        fn is_in_bounds(&self, len: usize) -> bool {
+            ops::RangeInclusive::from(*self).is_in_bounds(len)
+        }
+    }
     #[unstable(feature = "new_range_api", issue = "125687")]
-    impl Sealed for range::RangeFrom<usize> {}
+    impl Sealed for range::RangeFrom<usize> {
+        fn is_in_bounds(&self, len: usize) -> bool {
+            ops::RangeFrom::from(*self).is_in_bounds(len)
+        }
+    }
 
-    impl Sealed for ops::IndexRange {}
+    impl Sealed for ops::IndexRange {
+        fn is_in_bounds(&self, len: usize) -> bool {
+            self.end() <= len
+        }
+    }
 }
 
 /// A helper trait used for indexing operations.
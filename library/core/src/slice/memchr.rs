@@ -39,6 +39,8 @@ const fn memchr_naive(x: u8, text: &[u8]) -> Option<usize> {
     let mut i = 0;
 
     // FIXME(const-hack): Replace with `text.iter().pos(|c| *c == x)`.
+    #[safety::loop_invariant(i <= text.len() && safety::forall!(|j in 0..i| text[j] != x))]
+    #[safety::decreases(text.len() - i)]
     while i < text.len() {
         if text[i] == x {
             return Some(i);
@@ -80,6 +82,8 @@ const fn memchr_aligned(x: u8, text: &[u8]) -> Option<usize> {
 
     // search the body of the text
     let repeated_x = usize::repeat_u8(x);
+    #[safety::loop_invariant(offset <= len && safety::forall!(|j in 0..offset| text[j] != x))]
+    #[safety::decreases(len - offset)]
     while offset <= len - 2 * USIZE_BYTES {
         // SAFETY: the while's predicate guarantees a distance of at least 2 * usize_bytes
         // between the offset and the end of the slice.
@@ -138,6 +142,8 @@ pub fn memrchr(x: u8, text: &[u8]) -> Option<usize> {
     let repeated_x = usize::repeat_u8(x);
     let chunk_bytes = mem::size_of::<Chunk>();
 
+    #[safety::loop_invariant(offset <= len && safety::forall!(|j in offset..len| text[j] != x))]
+    #[safety::decreases(offset)]
     while offset > min_aligned_offset {
         // SAFETY: offset starts at len - suffix.len(), as long as it is greater than
         // min_aligned_offset (prefix.len()) the remaining distance is at least 2 * chunk_bytes.
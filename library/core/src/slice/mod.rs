@@ -6,6 +6,8 @@
 
 #![stable(feature = "rust1", since = "1.0.0")]
 
+use safety::{ensures, requires};
+
 use crate::cmp::Ordering::{self, Equal, Greater, Less};
 use crate::intrinsics::{exact_div, select_unpredictable, unchecked_sub};
 use crate::mem::{self, SizedTypeProperties};
@@ -13,7 +15,7 @@ use crate::num::NonZero;
 use crate::ops::{Bound, OneSidedRange, Range, RangeBounds};
 use crate::simd::{self, Simd};
 use crate::ub_checks::assert_unsafe_precondition;
-use crate::{fmt, hint, ptr, slice};
+use crate::{fmt, hint, ptr, slice, ub_checks};
 
 #[unstable(
     feature = "slice_internals",
@@ -3859,6 +3861,21 @@ impl<T> [T] {
     /// ```
     #[stable(feature = "slice_align_to", since = "1.30.0")]
     #[must_use]
+    #[requires(U::IS_ZST || T::IS_ZST || {
+        // The middle slice starts at the first element aligned for `U`, and its bytes must be
+        // valid `U`s.
+        let offset = self.as_ptr().align_offset(mem::align_of::<U>());
+        offset > self.len() || {
+            let (us_len, _) = self[offset..].align_to_offsets::<U>();
+            let middle = self.as_ptr().wrapping_add(offset).cast::<U>();
+            ub_checks::is_valid_value(ptr::slice_from_raw_parts(middle, us_len))
+        }
+    })]
+    #[ensures(|(prefix, middle, suffix): &(&[T], &[U], &[T])| {
+        mem::size_of_val(*prefix) + mem::size_of_val(*middle) + mem::size_of_val(*suffix)
+            == mem::size_of_val(self)
+            && middle.as_ptr().is_aligned()
+    })]
     pub unsafe fn align_to<U>(&self) -> (&[T], &[U], &[T]) {
         // Note that most of this function will be constant-evaluated,
         if U::IS_ZST || T::IS_ZST {
@@ -3924,6 +3941,16 @@ impl<T> [T] {
     /// ```
     #[stable(feature = "slice_align_to", since = "1.30.0")]
     #[must_use]
+    #[requires(U::IS_ZST || T::IS_ZST || {
+        // The middle slice starts at the first element aligned for `U`, and its bytes must be
+        // valid `U`s.
+        let offset = self.as_ptr().align_offset(mem::align_of::<U>());
+        offset > self.len() || {
+            let (us_len, _) = self[offset..].align_to_offsets::<U>();
+            let middle = self.as_ptr().wrapping_add(offset).cast::<U>();
+            ub_checks::is_valid_value(ptr::slice_from_raw_parts(middle, us_len))
+        }
+    })]
     pub unsafe fn align_to_mut<U>(&mut self) -> (&mut [T], &mut [U], &mut [T]) {
         // Note that most of this function will be constant-evaluated,
         if U::IS_ZST || T::IS_ZST {
@@ -4912,3 +4939,50 @@ impl<const N: usize> fmt::Display for GetManyMutError<N> {
         fmt::Display::fmt("an index is out of bounds or appeared multiple times in the array", f)
     }
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+
+    /// Length of the arrays the slices of the harnesses point into.
+    const ARRAY_LEN: usize = 8;
+
+    macro_rules! generate_align_to_harnesses {
+        ($module:ident, $src:ty, $dst:ty) => {
+            mod $module {
+                use super::*;
+
+                // pub unsafe fn align_to<U>(&self) -> (&[T], &[U], &[T])
+                #[safety::proof_for_contract(<[$src]>::align_to::<$dst>)]
+                fn check_align_to() {
+                    let array: [$src; ARRAY_LEN] = ub_checks::any();
+                    let start = ub_checks::any_in(0..=ARRAY_LEN);
+                    let _ = unsafe { array[start..].align_to::<$dst>() };
+                }
+
+                // pub unsafe fn align_to_mut<U>(&mut self) -> (&mut [T], &mut [U], &mut [T])
+                #[safety::proof_for_contract(<[$src]>::align_to_mut::<$dst>)]
+                fn check_align_to_mut() {
+                    let mut array: [$src; ARRAY_LEN] = ub_checks::any();
+                    let start = ub_checks::any_in(0..=ARRAY_LEN);
+                    let size = mem::size_of_val(&array[start..]);
+                    // The body returns reborrows of `self`, which `ensures` cannot wrap, so the
+                    // postcondition of `align_to` is checked here.
+                    let (prefix, middle, suffix) = unsafe { array[start..].align_to_mut::<$dst>() };
+                    let sizes = [mem::size_of_val(prefix), mem::size_of_val(middle)];
+                    assert_eq!(sizes[0] + sizes[1] + mem::size_of_val(suffix), size);
+                    assert!(middle.as_ptr().is_aligned());
+                    // The middle slice is made of the initialized bytes of `array`.
+                    assert!(ub_checks::is_valid_value(ptr::from_ref(middle)));
+                }
+            }
+        };
+    }
+
+    generate_align_to_harnesses!(check_u8_to_u16, u8, u16);
+    generate_align_to_harnesses!(check_u8_to_bool, u8, bool);
+    generate_align_to_harnesses!(check_u32_to_char, u32, char);
+    generate_align_to_harnesses!(check_u16_to_u8_array, u16, [u8; 3]);
+    generate_align_to_harnesses!(check_u8_to_unit, u8, ());
+}
@@ -591,6 +591,9 @@ pub fn insertion_sort_shift_left<T, F: FnMut(&T, &T) -> bool>(
         let v_base = v.as_mut_ptr();
         let v_end = v_base.add(len);
         let mut tail = v_base.add(offset);
+        // `insert_tail` needs `v_base < tail`, which holds until the loop is done.
+        #[safety::loop_invariant(tail == v_end || (v_base < tail && tail < v_end))]
+        #[safety::decreases(v_end.addr() - tail.addr())]
         while tail != v_end {
             // SAFETY: v_base and tail are both valid pointers to elements, and
             // v_base < tail since we checked offset != 0.
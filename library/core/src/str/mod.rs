@@ -13,6 +13,8 @@ mod iter;
 mod traits;
 mod validations;
 
+use safety::ensures;
+
 use self::pattern::{DoubleEndedSearcher, Pattern, ReverseSearcher, Searcher};
 use crate::char::{self, EscapeDebugExtArgs};
 use crate::ops::Range;
@@ -340,6 +342,8 @@ impl str {
     #[stable(feature = "str_mut_extras", since = "1.20.0")]
     #[must_use]
     #[inline(always)]
+    // The bytes are valid UTF-8 when they are lent; keeping them valid is up to the caller.
+    #[ensures(|result| from_utf8(result).is_ok())]
     pub unsafe fn as_bytes_mut(&mut self) -> &mut [u8] {
         // SAFETY: the cast from `&str` to `&[u8]` is safe since `str`
         // has the same layout as `&[u8]` (only std can make this guarantee).
@@ -2817,6 +2821,25 @@ impl_fn_for_zst! {
     };
 }
 
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+    use crate::ub_checks;
+
+    // pub unsafe fn as_bytes_mut(&mut self) -> &mut [u8]
+    #[safety::proof_for_contract(str::as_bytes_mut)]
+    fn check_as_bytes_mut() {
+        let mut bytes: [u8; 8] = ub_checks::any();
+        let len = ub_checks::any_in(0..=bytes.len());
+        if let Ok(s) = from_utf8_mut(&mut bytes[..len]) {
+            let s_ptr = s.as_ptr();
+            let result = unsafe { s.as_bytes_mut() };
+            assert_eq!((result.as_ptr(), result.len()), (s_ptr, len));
+        }
+    }
+}
+
 // This is required to make `impl From<&str> for Box<dyn Error>` and `impl<E> From<E> for Box<dyn Error>` not overlap.
 #[stable(feature = "error_in_core_neg_impl", since = "1.65.0")]
 impl !crate::error::Error for &str {}
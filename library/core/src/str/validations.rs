@@ -132,6 +132,8 @@ pub(super) const fn run_utf8_validation(v: &[u8]) -> Result<(), Utf8Error> {
     let blocks_end = if len >= ascii_block_size { len - ascii_block_size + 1 } else { 0 };
     let align = v.as_ptr().align_offset(usize_bytes);
 
+    #[safety::loop_invariant(index <= len)]
+    #[safety::decreases(len - index)]
     while index < len {
         let old_offset = index;
         macro_rules! err {
@@ -211,6 +213,10 @@ pub(super) const fn run_utf8_validation(v: &[u8]) -> Result<(), Utf8Error> {
             // until we find a word containing a non-ascii byte.
             if align != usize::MAX && align.wrapping_sub(index) % usize_bytes == 0 {
                 let ptr = v.as_ptr();
+                #[safety::loop_invariant(
+                    index <= len && align.wrapping_sub(index) % usize_bytes == 0
+                )]
+                #[safety::decreases(len - index)]
                 while index < blocks_end {
                     // SAFETY: since `align - index` and `ascii_block_size` are
                     // multiples of `usize_bytes`, `block = ptr.add(index)` is
@@ -228,6 +234,8 @@ pub(super) const fn run_utf8_validation(v: &[u8]) -> Result<(), Utf8Error> {
                     index += ascii_block_size;
                 }
                 // step from the point where the wordwise loop stopped
+                #[safety::loop_invariant(index <= len)]
+                #[safety::decreases(len - index)]
                 while index < len && v[index] < 128 {
                     index += 1;
                 }
@@ -271,3 +279,35 @@ pub const fn utf8_char_width(b: u8) -> usize {
 
 /// Mask of the value bits of a continuation byte.
 const CONT_MASK: u8 = 0b0011_1111;
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+    use crate::ub_checks;
+
+    // pub(super) const fn run_utf8_validation(v: &[u8]) -> Result<(), Utf8Error>
+    #[safety::harness]
+    fn check_run_utf8_validation() {
+        // Long enough to go through the word-at-a-time loop.
+        const LEN: usize = 3 * mem::size_of::<usize>();
+        let bytes: [u8; LEN] = ub_checks::any();
+        let len = ub_checks::any_in(0..=LEN);
+        let slice = &bytes[..len];
+        match run_utf8_validation(slice) {
+            Ok(()) => {
+                // The code points are decoded as integers, so they are checked to be Unicode
+                // scalar values before any `char` is materialised.
+                let mut bytes = slice.iter();
+                // SAFETY: the bytes were just validated.
+                while let Some(code) = unsafe { next_code_point(&mut bytes) } {
+                    assert!(code <= 0x10FFFF && !(0xD800..=0xDFFF).contains(&code));
+                }
+            }
+            Err(err) => {
+                assert!(err.valid_up_to() < len);
+                assert!(run_utf8_validation(&slice[..err.valid_up_to()]).is_ok());
+            }
+        }
+    }
+}
@@ -22,6 +22,10 @@
 use crate::fmt;
 use crate::iter::Sum;
 use crate::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+use crate::ub_checks;
+
+#[cfg(kani)]
+use crate::kani;
 
 const NANOS_PER_SEC: u32 = 1_000_000_000;
 const NANOS_PER_MILLI: u32 = 1_000_000;
@@ -93,6 +97,7 @@ impl Default for Nanoseconds {
 /// compatibility, you may wish to format `Duration` objects yourself or use a
 /// crate to do so.
 #[stable(feature = "duration", since = "1.3.0")]
+#[safety::invariant(self.nanos.0 < NANOS_PER_SEC)]
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 #[cfg_attr(not(test), rustc_diagnostic_item = "Duration")]
 pub struct Duration {
@@ -208,8 +213,9 @@ impl Duration {
     #[inline]
     #[must_use]
     #[rustc_const_stable(feature = "duration_consts_2", since = "1.58.0")]
+    #[rustc_allow_const_fn_unstable(const_ub_checks)]
     pub const fn new(secs: u64, nanos: u32) -> Duration {
-        if nanos < NANOS_PER_SEC {
+        let duration = if nanos < NANOS_PER_SEC {
             // SAFETY: nanos < NANOS_PER_SEC, therefore nanos is within the valid range
             Duration { secs, nanos: unsafe { Nanoseconds(nanos) } }
         } else {
@@ -220,7 +226,9 @@ impl Duration {
             let nanos = nanos % NANOS_PER_SEC;
             // SAFETY: nanos % NANOS_PER_SEC < NANOS_PER_SEC, therefore nanos is within the valid range
             Duration { secs, nanos: unsafe { Nanoseconds(nanos) } }
-        }
+        };
+        ub_checks::assert_invariant(&duration);
+        duration
     }
 
     /// Creates a new `Duration` from the specified number of whole seconds.
@@ -259,6 +267,7 @@ impl Duration {
     #[must_use]
     #[inline]
     #[rustc_const_stable(feature = "duration_consts", since = "1.32.0")]
+    #[rustc_allow_const_fn_unstable(const_ub_checks)]
     pub const fn from_millis(millis: u64) -> Duration {
         let secs = millis / MILLIS_PER_SEC;
         let subsec_millis = (millis % MILLIS_PER_SEC) as u32;
@@ -266,7 +275,9 @@ impl Duration {
         //         => x % 1_000 < 1_000
         let subsec_nanos = unsafe { Nanoseconds(subsec_millis * NANOS_PER_MILLI) };
 
-        Duration { secs, nanos: subsec_nanos }
+        let duration = Duration { secs, nanos: subsec_nanos };
+        ub_checks::assert_invariant(&duration);
+        duration
     }
 
     /// Creates a new `Duration` from the specified number of microseconds.
@@ -285,6 +296,7 @@ impl Duration {
     #[must_use]
     #[inline]
     #[rustc_const_stable(feature = "duration_consts", since = "1.32.0")]
+    #[rustc_allow_const_fn_unstable(const_ub_checks)]
     pub const fn from_micros(micros: u64) -> Duration {
         let secs = micros / MICROS_PER_SEC;
         let subsec_micros = (micros % MICROS_PER_SEC) as u32;
@@ -292,7 +304,9 @@ impl Duration {
         //         => x % 1_000_000 < 1_000_000
         let subsec_nanos = unsafe { Nanoseconds(subsec_micros * NANOS_PER_MICRO) };
 
-        Duration { secs, nanos: subsec_nanos }
+        let duration = Duration { secs, nanos: subsec_nanos };
+        ub_checks::assert_invariant(&duration);
+        duration
     }
 
     /// Creates a new `Duration` from the specified number of nanoseconds.
@@ -316,6 +330,7 @@ impl Duration {
     #[must_use]
     #[inline]
     #[rustc_const_stable(feature = "duration_consts", since = "1.32.0")]
+    #[rustc_allow_const_fn_unstable(const_ub_checks)]
     pub const fn from_nanos(nanos: u64) -> Duration {
         const NANOS_PER_SEC: u64 = self::NANOS_PER_SEC as u64;
         let secs = nanos / NANOS_PER_SEC;
@@ -323,7 +338,9 @@ impl Duration {
         // SAFETY: x % 1_000_000_000 < 1_000_000_000
         let subsec_nanos = unsafe { Nanoseconds(subsec_nanos) };
 
-        Duration { secs, nanos: subsec_nanos }
+        let duration = Duration { secs, nanos: subsec_nanos };
+        ub_checks::assert_invariant(&duration);
+        duration
     }
 
     /// Creates a new `Duration` from the specified number of weeks.
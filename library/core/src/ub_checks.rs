@@ -164,48 +164,669 @@ pub(crate) const fn is_nonoverlapping(
 
 pub use predicates::*;
 
+/// Checks that `value` satisfies its type invariant, if UB checks are enabled.
+///
+/// This is meant to be called at API boundaries of types that declare an invariant with
+/// `#[safety::invariant]`, e.g., by the constructors and the mutators of the type. The invariant
+/// is not checked during const evaluation, where `Invariant::is_safe` cannot be called.
+#[rustc_const_unstable(feature = "const_ub_checks", issue = "none")]
+#[inline]
+pub const fn assert_invariant<T: Invariant + ?Sized>(value: &T) {
+    #[inline]
+    fn runtime<T: Invariant + ?Sized>(value: &T) {
+        if !value.is_safe() {
+            crate::panicking::panic_nounwind("type invariant violated");
+        }
+    }
+
+    #[inline]
+    const fn comptime<T: Invariant + ?Sized>(_value: &T) {}
+
+    if check_library_ub() {
+        const_eval_select((value,), comptime, runtime);
+    }
+}
+
+/// Checks whether the `len` values of type `T` starting at `ptr` are initialized.
+///
+/// Under verification, this is decided by the shadow memory that tracks which bytes were written,
+/// taking the layout of `T` into account, so padding bytes do not need to be initialized. At
+/// runtime, the whole range is checked by the memory predicate hook, if one is installed, e.g., by
+/// querying a sanitizer. Without a hook, only the pointer and the size of the range are checked.
+#[inline]
+pub fn is_initialized<T>(ptr: *const T, len: usize) -> bool {
+    len.checked_mul(size_of::<T>()).is_some_and(|size| size <= isize::MAX as usize)
+        && can_read_unaligned(crate::ptr::slice_from_raw_parts(ptr, len))
+}
+
+/// Checks whether the all-zero bit pattern is a valid value of type `T`.
+///
+/// This does not hold, e.g., for references, function pointers and `NonZero` integers. Under
+/// verification, this checks the validity of a zeroed `T`. At runtime, validity cannot be
+/// checked, but `mem::zeroed` and similar functions already panic for types whose layout does
+/// not allow zero.
+#[inline]
+pub fn is_zero_valid<T>() -> bool {
+    is_valid_value(crate::mem::MaybeUninit::<T>::zeroed().as_ptr())
+}
+
+/// Checks whether leaving the bytes of a `T` uninitialized gives a valid value of type `T`.
+///
+/// This only holds for types such as `MaybeUninit` and `()`. Under verification, this checks the
+/// validity of an uninitialized `T`. At runtime, the bytes of a value are never found
+/// uninitialized, only unreadable, and an uninitialized `T` on the stack is always readable, so
+/// this holds without creating one.
+#[inline]
+pub fn is_uninit_valid<T>() -> bool {
+    !cfg!(kani) || is_valid_value(crate::mem::MaybeUninit::<T>::uninit().as_ptr())
+}
+
+/// Checks whether `a` and `b` are the same value.
+///
+/// Values can only be compared with `T: PartialEq`, and comparing their bytes is undefined
+/// behavior for the padding bytes `T` may have. So the values are compared if their equality is
+/// the equality of their bytes, e.g., for integers, `bool` and `char`, and arrays of them. For
+/// other types, only their discriminants are compared.
+#[inline]
+pub fn is_same_value<T>(a: &T, b: &T) -> bool {
+    <T as SameValue>::is_same_value(a, b)
+}
+
+trait SameValue {
+    fn is_same_value(&self, other: &Self) -> bool;
+}
+
+impl<T> SameValue for T {
+    default fn is_same_value(&self, other: &T) -> bool {
+        crate::mem::discriminant(self) == crate::mem::discriminant(other)
+    }
+}
+
+impl<T: crate::cmp::BytewiseEq> SameValue for T {
+    fn is_same_value(&self, other: &T) -> bool {
+        self == other
+    }
+}
+
+/// A bitwise copy of a value, which is never dropped.
+///
+/// This lets a postcondition compare a value to the one it had before the call with
+/// [`is_same_value`], as in `is_same_value(&*dest, old(Snapshot::of(&*dest)).get())`, without
+/// requiring `T: Clone` for `old`.
+pub struct Snapshot<T>(crate::mem::MaybeUninit<T>);
+
+impl<T> Snapshot<T> {
+    /// Copies the bytes of `value`.
+    #[inline]
+    pub fn of(value: &T) -> Self {
+        // SAFETY: `value` is valid for reads, and the copy is never used as an owned `T`.
+        Snapshot(unsafe { crate::ptr::read(crate::ptr::from_ref(value).cast()) })
+    }
+
+    /// Returns the copied value.
+    #[inline]
+    pub fn get(&self) -> &T {
+        // SAFETY: the bytes were copied from a valid `T`.
+        unsafe { self.0.assume_init_ref() }
+    }
+}
+
+impl<T> Clone for Snapshot<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Snapshot::of(self.get())
+    }
+}
+
+/// Checks whether `value` is finite and representable in `Int` after truncating off its fractional
+/// part, i.e., whether it can be converted with `to_int_unchecked`.
+#[inline]
+pub fn float_fits_in<Int>(value: impl crate::convert::FloatToInt<Int>) -> bool {
+    value.fits_in_int()
+}
+
 /// Provide a few predicates to be used in safety contracts.
 ///
-/// At runtime, they are no-op, and always return true.
+/// At runtime, they only check what can be decided locally: that the pointer is non-null,
+/// properly aligned (when required) and that the accessed range does not overflow the address
+/// space. Whether the memory is allocated and initialized is delegated to an optional hook
+/// installed with [`set_mem_predicate_hook`], which can be backed by a sanitizer or a test
+/// allocator. Without a hook, those properties are assumed to hold.
+///
+/// The size and alignment of the pointee are only computed from metadata that passes
+/// [`has_valid_metadata`], so every predicate is false for a pointer with invalid metadata.
 #[cfg(not(kani))]
 mod predicates {
+    use crate::mem::{align_of_val_raw, size_of_val_raw};
+    use super::is_aligned_and_not_null;
+
     /// Checks if a pointer can be dereferenced, ensuring:
     ///   * `src` is valid for reads (see [`crate::ptr`] documentation).
     ///   * `src` is properly aligned (use `read_unaligned` if not).
     ///   * `src` points to a properly initialized value of type `T`.
     ///
     /// [`crate::ptr`]: https://doc.rust-lang.org/std/ptr/index.html
-    pub fn can_dereference<T>(src: *const T) -> bool {
-        let _ = src;
-        true
+    pub fn can_dereference<T: ?Sized>(src: *const T) -> bool {
+        size_and_align(src).is_some_and(|(size, align)| {
+            is_aligned_and_not_null(src as *const (), align)
+                && has_range_access(src as *const (), size, MemAccess::Read)
+        })
     }
 
     /// Check if a pointer can be written to:
     /// * `dst` must be valid for writes.
     /// * `dst` must be properly aligned. Use `write_unaligned` if this is not the
     ///    case.
-    pub fn can_write<T>(dst: *mut T) -> bool {
-        let _ = dst;
-        true
+    pub fn can_write<T: ?Sized>(dst: *mut T) -> bool {
+        size_and_align(dst).is_some_and(|(size, align)| {
+            is_aligned_and_not_null(dst as *const (), align)
+                && has_range_access(dst as *const (), size, MemAccess::Write)
+        })
     }
 
     /// Check if a pointer can be the target of unaligned reads.
     /// * `src` must be valid for reads.
     /// * `src` must point to a properly initialized value of type `T`.
-    pub fn can_read_unaligned<T>(src: *const T) -> bool {
-        let _ = src;
-        true
+    pub fn can_read_unaligned<T: ?Sized>(src: *const T) -> bool {
+        size_and_align(src).is_some_and(|(size, _)| {
+            !src.is_null() && has_range_access(src as *const (), size, MemAccess::Read)
+        })
+    }
+
+    /// Checks if the bytes `src` points to are a valid value of type `T`, i.e., whether they can
+    /// be read or transmuted as a `T`. `src` does not need to be aligned.
+    ///
+    /// At runtime, the validity invariant of `T` is unknown, e.g., that a `bool` is `0` or `1`,
+    /// so this only checks that the bytes can be read, same as [`can_read_unaligned`].
+    pub fn is_valid_value<T: ?Sized>(src: *const T) -> bool {
+        can_read_unaligned(src)
     }
 
     /// Check if a pointer can be the target of unaligned writes.
     /// * `dst` must be valid for writes.
-    pub fn can_write_unaligned<T>(dst: *mut T) -> bool {
-        let _ = dst;
+    pub fn can_write_unaligned<T: ?Sized>(dst: *mut T) -> bool {
+        size_and_align(dst).is_some_and(|(size, _)| {
+            !dst.is_null() && has_range_access(dst as *const (), size, MemAccess::Write)
+        })
+    }
+
+    /// Checks if two pointers point into the same allocated object, or one past its end.
+    ///
+    /// At runtime, this checks that the range of bytes between the two pointers can be accessed
+    /// as a whole, which may also hold for adjacent allocations.
+    pub fn same_allocation<T: ?Sized>(src: *const T, dst: *const T) -> bool {
+        let (start, end) = (src.addr().min(dst.addr()), src.addr().max(dst.addr()));
+        let start_ptr = src.cast::<()>().with_addr(start);
+        // `Write` only requires the bytes to be allocated, not initialized.
+        start == end || (start != 0 && has_range_access(start_ptr, end - start, MemAccess::Write))
+    }
+
+    /// Checks if the metadata of `ptr` is valid, i.e., whether the size and alignment of the value
+    /// it points to can be computed, and its size does not exceed `isize::MAX`.
+    ///
+    /// At runtime, only the length of slices and string slices is checked. The vtable of a trait
+    /// object can only be obtained from a valid pointer, so it is assumed to be valid, and so is
+    /// the length of the slice at the end of a custom unsized type.
+    pub fn has_valid_metadata<T: ?Sized>(ptr: *const T) -> bool {
+        <T as CheckMetadata>::has_valid_metadata(ptr)
+    }
+
+    /// Pointees whose metadata can be checked at runtime.
+    trait CheckMetadata {
+        fn has_valid_metadata(ptr: *const Self) -> bool;
+    }
+
+    impl<T: ?Sized> CheckMetadata for T {
+        default fn has_valid_metadata(_: *const T) -> bool {
+            true
+        }
+    }
+
+    impl<T> CheckMetadata for [T] {
+        fn has_valid_metadata(ptr: *const [T]) -> bool {
+            // `len` calls `ptr::metadata`, whose contract calls this predicate, so the length is
+            // read with the intrinsic instead.
+            let len = crate::intrinsics::ptr_metadata(ptr);
+            len.checked_mul(size_of::<T>()).is_some_and(|size| size <= isize::MAX as usize)
+        }
+    }
+
+    impl CheckMetadata for str {
+        fn has_valid_metadata(ptr: *const str) -> bool {
+            <[u8] as CheckMetadata>::has_valid_metadata(ptr as *const [u8])
+        }
+    }
+
+    /// A type with an invariant that every safe value must satisfy.
+    ///
+    /// Implementations are usually generated with `#[safety::invariant]`.
+    pub trait Invariant {
+        /// Returns whether `self` satisfies the type invariant.
+        fn is_safe(&self) -> bool;
+    }
+
+    /// The kind of access a predicate is checking for.
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    pub enum MemAccess {
+        /// The range must be allocated and initialized.
+        Read,
+        /// The range must be allocated.
+        Write,
+    }
+
+    /// A hook that decides whether `size` bytes starting at `ptr` can be accessed as `access`.
+    pub type MemPredicateHook = fn(ptr: *const (), size: usize, access: MemAccess) -> bool;
+
+    #[cfg(target_has_atomic = "ptr")]
+    static HOOK: crate::sync::atomic::AtomicPtr<()> =
+        crate::sync::atomic::AtomicPtr::new(crate::ptr::null_mut());
+
+    /// Registers a hook used by the memory predicates to check that a range of memory is
+    /// allocated (and initialized, for reads), replacing any that was previously registered.
+    ///
+    /// Passing `None` unregisters the current hook. On targets without pointer-sized atomics,
+    /// the hook is ignored.
+    pub fn set_mem_predicate_hook(hook: Option<MemPredicateHook>) {
+        #[cfg(target_has_atomic = "ptr")]
+        {
+            let hook = match hook {
+                Some(hook) => hook as *mut (),
+                None => crate::ptr::null_mut(),
+            };
+            HOOK.store(hook, crate::sync::atomic::Ordering::Release);
+        }
+        #[cfg(not(target_has_atomic = "ptr"))]
+        let _ = hook;
+    }
+
+    /// The size and alignment of the value `ptr` points to, if its metadata is valid.
+    fn size_and_align<T: ?Sized>(ptr: *const T) -> Option<(usize, usize)> {
+        if !has_valid_metadata(ptr) {
+            return None;
+        }
+        // SAFETY: we just checked that the metadata of `ptr` is valid.
+        unsafe { Some((size_of_val_raw(ptr), align_of_val_raw(ptr))) }
+    }
+
+    /// Checks that the `size` bytes starting at `ptr` do not wrap around the address space, and
+    /// asks the registered hook, if any, whether they can be accessed.
+    fn has_range_access(ptr: *const (), size: usize, access: MemAccess) -> bool {
+        if ptr.addr().checked_add(size).is_none() {
+            return false;
+        }
+        if size == 0 {
+            return true;
+        }
+        #[cfg(target_has_atomic = "ptr")]
+        {
+            let hook = HOOK.load(crate::sync::atomic::Ordering::Acquire);
+            if !hook.is_null() {
+                // SAFETY: `HOOK` is only ever set from a valid `MemPredicateHook`.
+                let hook: MemPredicateHook = unsafe { crate::mem::transmute(hook) };
+                return hook(ptr, size, access);
+            }
+        }
+        let _ = access;
         true
     }
 }
 
 #[cfg(kani)]
 mod predicates {
-    pub use crate::kani::mem::{can_dereference, can_write, can_read_unaligned, can_write_unaligned};
+    pub use crate::kani::mem::{
+        can_dereference, can_read_unaligned, can_write, can_write_unaligned, same_allocation,
+    };
+    pub use crate::kani::Invariant;
+
+    /// Kani checks the bytes against the validity invariant of `T`, e.g., that a `char` is a
+    /// Unicode scalar value, in addition to checking that they are in bounds and initialized.
+    pub fn is_valid_value<T: ?Sized>(src: *const T) -> bool {
+        crate::kani::mem::can_read_unaligned(src)
+    }
+
+    pub fn has_valid_metadata<T: ?Sized>(ptr: *const T) -> bool {
+        crate::kani::mem::checked_size_of_raw(ptr).is_some()
+            && crate::kani::mem::checked_align_of_raw(ptr).is_some()
+    }
+}
+
+pub use ghost::GhostFlag;
+
+/// Provide ghost state, i.e., state that is only tracked by verification tools.
+///
+/// Ghost state is updated with `#[safety::ghost]` statements, which are erased at runtime. The
+/// state is then unknown, so every query about it holds, similar to the memory predicates without
+/// a hook.
+#[cfg(not(kani))]
+mod ghost {
+    /// A boolean flag attached to memory locations, e.g., to track whether the value stored at a
+    /// location was moved out and must not be used again.
+    pub struct GhostFlag {}
+
+    impl GhostFlag {
+        /// Creates a flag that is unset for every location.
+        pub const fn new() -> Self {
+            GhostFlag {}
+        }
+
+        /// Checks if the flag is set for the location `ptr` points to.
+        pub fn is_set<T: ?Sized>(&self, ptr: *const T) -> bool {
+            let _ = ptr;
+            true
+        }
+
+        /// Checks if the flag is unset for the location `ptr` points to.
+        pub fn is_unset<T: ?Sized>(&self, ptr: *const T) -> bool {
+            let _ = ptr;
+            true
+        }
+
+        /// Sets the flag for the location `ptr` points to.
+        pub fn set<T: ?Sized>(&self, ptr: *const T) {
+            let _ = ptr;
+        }
+
+        /// Unsets the flag for the location `ptr` points to, e.g., when it is reinitialized.
+        pub fn unset<T: ?Sized>(&self, ptr: *const T) {
+            let _ = ptr;
+        }
+    }
+}
+
+#[cfg(kani)]
+mod ghost {
+    use crate::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+    /// A boolean flag attached to memory locations.
+    ///
+    /// The flag is only stored for one location, whose address is picked arbitrarily the first
+    /// time the flag is set or unset. Kani explores every address, so every location is covered
+    /// without bounding how many locations have the flag set. The flag of the other locations is
+    /// unknown, so every query about them holds, same as at runtime. Zero-sized locations are
+    /// never tracked, since they can share their address with other values.
+    pub struct GhostFlag {
+        /// Whether the address of the tracked location was picked.
+        picked: AtomicBool,
+        /// The address of the tracked location.
+        addr: AtomicUsize,
+        /// Whether the flag is set for the tracked location.
+        value: AtomicBool,
+    }
+
+    impl GhostFlag {
+        pub const fn new() -> Self {
+            GhostFlag {
+                picked: AtomicBool::new(false),
+                addr: AtomicUsize::new(0),
+                value: AtomicBool::new(false),
+            }
+        }
+
+        pub fn is_set<T: ?Sized>(&self, ptr: *const T) -> bool {
+            // Until an address is picked, the flag was never set.
+            if self.tracks(ptr) { self.value.load(Ordering::Relaxed) } else { self.is_picked() }
+        }
+
+        pub fn is_unset<T: ?Sized>(&self, ptr: *const T) -> bool {
+            !self.tracks(ptr) || !self.value.load(Ordering::Relaxed)
+        }
+
+        pub fn set<T: ?Sized>(&self, ptr: *const T) {
+            self.store(ptr, true);
+        }
+
+        pub fn unset<T: ?Sized>(&self, ptr: *const T) {
+            self.store(ptr, false);
+        }
+
+        fn store<T: ?Sized>(&self, ptr: *const T, value: bool) {
+            if !self.picked.swap(true, Ordering::Relaxed) {
+                self.addr.store(crate::kani::any(), Ordering::Relaxed);
+            }
+            if self.tracks(ptr) {
+                self.value.store(value, Ordering::Relaxed);
+            }
+        }
+
+        fn is_picked(&self) -> bool {
+            self.picked.load(Ordering::Relaxed)
+        }
+
+        fn tracks<T: ?Sized>(&self, ptr: *const T) -> bool {
+            self.is_picked()
+                && ptr.addr() == self.addr.load(Ordering::Relaxed)
+                && crate::kani::mem::checked_size_of_raw(ptr).is_some_and(|size| size != 0)
+        }
+    }
+}
+
+#[cfg(any(kani, target_has_atomic = "ptr"))]
+pub use arbitrary::*;
+
+/// Provide a generator of arbitrary values to be used in harnesses.
+///
+/// At runtime, values are drawn from a deterministic pseudo-random number generator, so harnesses
+/// annotated with `#[safety::harness]` can run as property-based tests.
+#[cfg(all(not(kani), target_has_atomic = "ptr"))]
+mod arbitrary {
+    use crate::ops::{Bound, RangeBounds};
+    use crate::sync::atomic::{AtomicUsize, Ordering};
+
+    /// A type whose values can be generated by [`any`].
+    pub trait Arbitrary: Sized {
+        /// Generate an arbitrary value of this type.
+        fn any() -> Self;
+    }
+
+    /// Generate an arbitrary value of type `T`.
+    #[inline]
+    pub fn any<T: Arbitrary>() -> T {
+        T::any()
+    }
+
+    /// Generate an arbitrary value of type `T` that satisfies `constraint`.
+    ///
+    /// Values are generated until one satisfies `constraint`, so it should hold for a reasonable
+    /// fraction of the values of `T`. Use [`any_in`] to constrain a value to a range instead.
+    pub fn any_where<T: Arbitrary, F: Fn(&T) -> bool>(constraint: F) -> T {
+        loop {
+            let value = T::any();
+            if constraint(&value) {
+                return value;
+            }
+        }
+    }
+
+    /// A type whose values can be generated in a range by [`any_in`].
+    pub trait ArbitraryInRange: Arbitrary {
+        /// Generate an arbitrary value between `start` and `end`, which must not be empty.
+        fn any_in_bounds(start: Bound<&Self>, end: Bound<&Self>) -> Self;
+    }
+
+    /// Generate an arbitrary value of type `T` in `range`, which must not be empty.
+    ///
+    /// Values are drawn from `range` directly, so that every value of a narrow range is generated,
+    /// whereas `any_where(|value| range.contains(value))` mostly generates the same few values.
+    pub fn any_in<T: ArbitraryInRange, R: RangeBounds<T>>(range: R) -> T {
+        T::any_in_bounds(range.start_bound(), range.end_bound())
+    }
+
+    // Each thread has its own generator where possible, so that harnesses running concurrently as
+    // unit tests do not interfere with each other.
+    #[cfg_attr(target_thread_local, thread_local)]
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    /// Reset the state of the generator used by [`any`] on the current thread.
+    ///
+    /// Harnesses that run as unit tests call this with a seed derived from their name, so every
+    /// run generates the same values.
+    pub fn set_any_seed(seed: usize) {
+        COUNTER.store(seed, Ordering::Relaxed);
+    }
+
+    /// SplitMix64, which only needs a counter as its state.
+    fn next_u64() -> u64 {
+        const GAMMA: u64 = 0x9e37_79b9_7f4a_7c15;
+        let counter = COUNTER.fetch_add(1, Ordering::Relaxed) as u64;
+        let mut z = counter.wrapping_add(1).wrapping_mul(GAMMA);
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    macro_rules! impl_arbitrary_int {
+        ($($ty:ty),*) => {
+            $(
+                impl Arbitrary for $ty {
+                    #[inline]
+                    fn any() -> Self {
+                        // Favor boundary values, which is where most bugs hide.
+                        match next_u64() % 8 {
+                            0 => <$ty>::MIN,
+                            1 => <$ty>::MAX,
+                            2 => 0,
+                            _ => next_u64() as $ty,
+                        }
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_arbitrary_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+    macro_rules! impl_arbitrary_in_range_int {
+        ($($ty:ty => $unsigned:ty),*) => {
+            $(
+                impl ArbitraryInRange for $ty {
+                    fn any_in_bounds(start: Bound<&Self>, end: Bound<&Self>) -> Self {
+                        let start = match start {
+                            Bound::Included(&start) => Some(start),
+                            Bound::Excluded(&start) => start.checked_add(1),
+                            Bound::Unbounded => Some(<$ty>::MIN),
+                        };
+                        let end = match end {
+                            Bound::Included(&end) => Some(end),
+                            Bound::Excluded(&end) => end.checked_sub(1),
+                            Bound::Unbounded => Some(<$ty>::MAX),
+                        };
+                        let (Some(start), Some(end)) = (start, end) else {
+                            panic!("cannot generate a value in an empty range");
+                        };
+                        assert!(start <= end, "cannot generate a value in an empty range");
+                        // Favor the bounds, same as `any` favors the boundary values.
+                        match next_u64() % 8 {
+                            0 => start,
+                            1 => end,
+                            _ => {
+                                // The difference of the bounds always fits in the unsigned type.
+                                let span = end.wrapping_sub(start) as $unsigned as u128;
+                                let random = ((next_u64() as u128) << 64) | next_u64() as u128;
+                                let offset = match span.checked_add(1) {
+                                    Some(len) => random % len,
+                                    None => random,
+                                };
+                                start.wrapping_add(offset as $unsigned as $ty)
+                            }
+                        }
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_arbitrary_in_range_int!(
+        u8 => u8, u16 => u16, u32 => u32, u64 => u64, u128 => u128, usize => usize,
+        i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize
+    );
+
+    impl Arbitrary for u128 {
+        #[inline]
+        fn any() -> Self {
+            ((next_u64() as u128) << 64) | next_u64() as u128
+        }
+    }
+
+    impl Arbitrary for i128 {
+        #[inline]
+        fn any() -> Self {
+            u128::any() as i128
+        }
+    }
+
+    impl Arbitrary for bool {
+        #[inline]
+        fn any() -> Self {
+            next_u64() & 1 == 1
+        }
+    }
+
+    impl Arbitrary for char {
+        #[inline]
+        fn any() -> Self {
+            loop {
+                if let Some(c) = char::from_u32(next_u64() as u32 % (char::MAX as u32 + 1)) {
+                    return c;
+                }
+            }
+        }
+    }
+
+    impl Arbitrary for f16 {
+        #[inline]
+        fn any() -> Self {
+            f16::from_bits(next_u64() as u16)
+        }
+    }
+
+    impl Arbitrary for f32 {
+        #[inline]
+        fn any() -> Self {
+            f32::from_bits(next_u64() as u32)
+        }
+    }
+
+    impl Arbitrary for f64 {
+        #[inline]
+        fn any() -> Self {
+            f64::from_bits(next_u64())
+        }
+    }
+
+    impl Arbitrary for f128 {
+        #[inline]
+        fn any() -> Self {
+            f128::from_bits(u128::any())
+        }
+    }
+
+    impl Arbitrary for () {
+        #[inline]
+        fn any() -> Self {}
+    }
+
+    impl<T: Arbitrary> Arbitrary for Option<T> {
+        #[inline]
+        fn any() -> Self {
+            if bool::any() { Some(T::any()) } else { None }
+        }
+    }
+
+    impl<T: Arbitrary, const N: usize> Arbitrary for [T; N] {
+        #[inline]
+        fn any() -> Self {
+            crate::array::from_fn(|_| T::any())
+        }
+    }
+}
+
+#[cfg(kani)]
+mod arbitrary {
+    pub use crate::kani::{any, any_where, Arbitrary};
+    use crate::ops::RangeBounds;
+
+    /// Generate an arbitrary value of type `T` in `range`.
+    pub fn any_in<T: Arbitrary + PartialOrd, R: RangeBounds<T>>(range: R) -> T {
+        any_where(|value| range.contains(value))
+    }
 }
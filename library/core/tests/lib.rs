@@ -87,6 +87,8 @@
 #![feature(pattern)]
 #![feature(pointer_is_aligned_to)]
 #![feature(portable_simd)]
+#![feature(ptr_alignment_type)]
+#![feature(ptr_internals)]
 #![feature(ptr_metadata)]
 #![feature(slice_from_ptr_range)]
 #![feature(slice_internals)]
@@ -107,6 +109,7 @@
 #![feature(try_blocks)]
 #![feature(try_find)]
 #![feature(try_trait_v2)]
+#![feature(ub_checks)]
 #![feature(unsigned_is_multiple_of)]
 #![feature(unsize)]
 #![feature(unsized_tuple_coercion)]
@@ -160,7 +163,9 @@ mod str_lossy;
 mod task;
 mod time;
 mod tuple;
+mod ub_checks;
 mod unicode;
+mod verify;
 mod waker;
 
 /// Copied from `std::test_helpers::test_rng`, see that function for rationale.
@@ -0,0 +1,154 @@
+use core::ptr::addr_of;
+use core::ub_checks::{
+    can_dereference, can_read_unaligned, can_write, can_write_unaligned, float_fits_in,
+    has_valid_metadata, is_initialized, is_valid_value, same_allocation, set_mem_predicate_hook,
+    MemAccess,
+};
+
+#[test]
+fn test_predicates_null_and_alignment() {
+    let mut x = [0u32; 2];
+    let ptr = x.as_mut_ptr();
+    assert!(can_dereference(ptr));
+    assert!(can_write(ptr));
+
+    let misaligned = ptr.cast::<u8>().wrapping_add(1).cast::<u32>();
+    assert!(!can_dereference(misaligned));
+    assert!(!can_write(misaligned));
+    assert!(can_read_unaligned(misaligned));
+    assert!(can_write_unaligned(misaligned));
+
+    assert!(!can_dereference(core::ptr::null::<u32>()));
+    assert!(!can_write_unaligned(core::ptr::null_mut::<u32>()));
+}
+
+#[test]
+fn test_predicates_address_overflow() {
+    assert!(!can_read_unaligned(usize::MAX as *const u16));
+    assert!(!can_write_unaligned((usize::MAX - 2) as *mut u32));
+    // Zero-sized accesses never overflow.
+    assert!(can_dereference(usize::MAX as *const ()));
+}
+
+#[test]
+fn test_predicates_unsized() {
+    use core::ptr::slice_from_raw_parts;
+
+    let x = [0u32; 4];
+    assert!(can_dereference(&x[1..] as *const [u32]));
+    let misaligned = x.as_ptr().cast::<u8>().wrapping_add(1).cast::<u32>();
+    assert!(!can_dereference(slice_from_raw_parts(misaligned, 2)));
+    assert!(can_read_unaligned(slice_from_raw_parts(misaligned, 2)));
+
+    let end = (usize::MAX - 4) as *const u8;
+    assert!(can_read_unaligned(slice_from_raw_parts(end, 4)));
+    assert!(!can_read_unaligned(slice_from_raw_parts(end, 8)));
+
+    assert!(can_dereference(&x as &dyn core::fmt::Debug as *const dyn core::fmt::Debug));
+}
+
+#[test]
+fn test_has_valid_metadata() {
+    use core::ptr::slice_from_raw_parts;
+
+    let x = [0u32; 4];
+    assert!(has_valid_metadata(&x[1..] as *const [u32]));
+    assert!(has_valid_metadata(slice_from_raw_parts(x.as_ptr(), isize::MAX as usize / 4)));
+    // The size of the pointee must not exceed `isize::MAX`, otherwise no predicate holds.
+    let huge = slice_from_raw_parts(x.as_ptr(), isize::MAX as usize / 2);
+    assert!(!has_valid_metadata(huge));
+    assert!(!can_read_unaligned(huge));
+    let bytes = slice_from_raw_parts(x.as_ptr().cast::<u8>(), usize::MAX);
+    assert!(!has_valid_metadata(bytes as *const str));
+}
+
+#[test]
+fn test_is_valid_value() {
+    let bytes = [1u8, 0, 0, 0, 0];
+    // Values do not need to be aligned.
+    assert!(is_valid_value(bytes.as_ptr().wrapping_add(1).cast::<u32>()));
+    assert!(is_valid_value(&bytes[..] as *const [u8] as *const str));
+    assert!(!is_valid_value(core::ptr::null::<bool>()));
+}
+
+#[test]
+fn test_is_initialized() {
+    let x = [0u16; 4];
+    assert!(is_initialized(x.as_ptr(), 4));
+    assert!(is_initialized(x.as_ptr().wrapping_add(4), 0));
+    assert!(!is_initialized(core::ptr::null::<u16>(), 1));
+    // The size of the range must not overflow.
+    assert!(!is_initialized(x.as_ptr(), usize::MAX / 2 + 1));
+}
+
+#[test]
+fn test_float_fits_in() {
+    // The fractional part is truncated off.
+    assert!(float_fits_in::<u8>(255.9f32));
+    assert!(!float_fits_in::<u8>(256.0f32));
+    assert!(float_fits_in::<u8>(-0.9f64));
+    assert!(!float_fits_in::<u8>(-1.0f64));
+    assert!(float_fits_in::<i8>(-128.9f32));
+    assert!(!float_fits_in::<i8>(-129.0f32));
+    // `i32::MIN - 1` is not representable as an `f32`.
+    assert!(float_fits_in::<i32>(i32::MIN as f32));
+    assert!(!float_fits_in::<i32>(2147483648.0f32));
+    assert!(float_fits_in::<u128>(f32::MAX));
+    assert!(!float_fits_in::<u64>(f64::NAN));
+    assert!(!float_fits_in::<i128>(f64::NEG_INFINITY));
+}
+
+#[test]
+fn test_same_allocation() {
+    let x = [0u16; 4];
+    let start = x.as_ptr();
+    let end = start.wrapping_add(4);
+    assert!(same_allocation(start, end));
+    assert!(same_allocation(end, start.wrapping_add(1)));
+    assert!(same_allocation(core::ptr::null::<u8>(), core::ptr::null()));
+    assert!(!same_allocation(core::ptr::null::<u8>(), 8 as *const u8));
+    assert!(same_allocation(&x[1..] as *const [u16], &x[..] as *const [u16]));
+}
+
+#[test]
+fn test_predicates_hook() {
+    static SENTINEL: u64 = 0;
+    // Only reject the sentinel, since other tests may run concurrently.
+    fn hook(ptr: *const (), size: usize, access: MemAccess) -> bool {
+        !(ptr == addr_of!(SENTINEL).cast() && size == 8 && access == MemAccess::Write)
+    }
+
+    let ptr = addr_of!(SENTINEL);
+    assert!(can_write(ptr.cast_mut()));
+    set_mem_predicate_hook(Some(hook));
+    assert!(can_dereference(ptr));
+    assert!(!can_write(ptr.cast_mut()));
+    set_mem_predicate_hook(None);
+    assert!(can_write(ptr.cast_mut()));
+}
+
+#[test]
+fn test_any() {
+    let values: [char; 16] = core::ub_checks::any();
+    assert!(values.iter().all(|c| char::from_u32(*c as u32).is_some()));
+
+    let even = core::ub_checks::any_where(|x: &u64| x % 2 == 0);
+    assert_eq!(even % 2, 0);
+
+    // Every value of a narrow range is generated.
+    let mut seen = [false; 5];
+    for _ in 0..100 {
+        seen[core::ub_checks::any_in(0..5usize)] = true;
+    }
+    assert!(seen.iter().all(|seen| *seen));
+    assert!((-3..=2).contains(&core::ub_checks::any_in(-3..=2i8)));
+    assert_eq!(core::ub_checks::any_in(u128::MAX..), u128::MAX);
+}
+
+#[test]
+fn test_any_seed() {
+    core::ub_checks::set_any_seed(42);
+    let values: [u64; 4] = safety::any!();
+    core::ub_checks::set_any_seed(42);
+    assert_eq!(safety::any!([u64; 4]), values);
+}
@@ -0,0 +1,12 @@
+//! Harnesses of `core` that only use its public API.
+//!
+//! `core` has no unit tests of its own, so the `#[cfg(kani)] mod verify` modules of the crate are
+//! only compiled for Kani, and their harnesses never run as unit tests. The few modules that only
+//! use the public API are included here as well, where they run as randomized unit tests.
+
+#[path = "../../src/ptr/alignment/verify.rs"]
+mod alignment;
+#[path = "../../src/alloc/layout/verify.rs"]
+mod layout;
+#[path = "../../src/ptr/unique/verify.rs"]
+mod unique;
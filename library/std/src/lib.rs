@@ -261,6 +261,7 @@
 #![allow(unused_features)]
 //
 // Features:
+#![cfg_attr(kani, feature(kani))]
 #![cfg_attr(test, feature(internal_output_capture, print_internals, update_panic_count, rt))]
 #![cfg_attr(
     all(target_vendor = "fortanix", target_env = "sgx"),
@@ -307,6 +308,7 @@
 #![feature(no_sanitize)]
 #![feature(optimize_attribute)]
 #![feature(prelude_import)]
+#![feature(proc_macro_hygiene)]
 #![feature(rustc_attrs)]
 #![feature(rustdoc_internals)]
 #![feature(staged_api)]
@@ -458,6 +460,10 @@ extern crate miniz_oxide;
 #[cfg(test)]
 extern crate std as realstd;
 
+// Verification support from `core`, available under the same paths as in `core` so that
+// contracts and `mod verify` blocks can be written the same way in this crate.
+safety::verify_lib!(std);
+
 // The standard macros that are not built-in to the compiler.
 #[macro_use]
 mod macros;
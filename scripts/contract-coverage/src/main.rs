@@ -0,0 +1,186 @@
+//! List the unsafe functions of the standard library, and whether they have contracts and
+//! contract harnesses.
+//!
+//! ```text
+//! contract-coverage <library-dir> [--crate <name>]... [--json <file>] [--markdown <file>]
+//!                   [--challenge <file.md>]
+//! ```
+//!
+//! Without `--json` nor `--markdown`, the markdown table is printed to stdout. With
+//! `--challenge`, only the functions listed in the tables of the given challenge are reported,
+//! and the tool exits with an error if any of them is missing a contract or a harness.
+
+mod report;
+mod scan;
+
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::{env, fs};
+
+use scan::Scan;
+
+const DEFAULT_CRATES: [&str; 3] = ["core", "alloc", "std"];
+
+const USAGE: &str = "usage: contract-coverage <library-dir> [--crate <name>]... \
+                     [--json <file>] [--markdown <file>] [--challenge <file.md>]";
+
+#[derive(Default)]
+struct Args {
+    library: PathBuf,
+    crates: Vec<String>,
+    json: Option<PathBuf>,
+    markdown: Option<PathBuf>,
+    challenge: Option<PathBuf>,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut args = Args::default();
+    let mut library = None;
+    let mut iter = env::args().skip(1);
+    while let Some(arg) = iter.next() {
+        let mut value = || {
+            iter.next()
+                .ok_or_else(|| format!("missing value for `{arg}`"))
+        };
+        match arg.as_str() {
+            "--crate" => args.crates.push(value()?),
+            "--json" => args.json = Some(value()?.into()),
+            "--markdown" => args.markdown = Some(value()?.into()),
+            "--challenge" => args.challenge = Some(value()?.into()),
+            "-h" | "--help" => return Err(USAGE.to_string()),
+            _ if arg.starts_with('-') => return Err(format!("unknown option `{arg}`\n{USAGE}")),
+            _ if library.is_none() => library = Some(arg.into()),
+            _ => return Err(USAGE.to_string()),
+        }
+    }
+    args.library = library.ok_or(USAGE)?;
+    if args.crates.is_empty() {
+        args.crates = DEFAULT_CRATES
+            .iter()
+            .map(|krate| krate.to_string())
+            .collect();
+    }
+    Ok(args)
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(code) => code,
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run() -> Result<ExitCode, String> {
+    let args = parse_args()?;
+    let mut scan = Scan::default();
+    for krate in &args.crates {
+        let src = args.library.join(krate).join("src");
+        if !src.is_dir() {
+            return Err(format!("`{}` is not a directory", src.display()));
+        }
+        scan_dir(krate, &src, &src, &mut scan)?;
+    }
+    scan.drop_verification_functions();
+    scan.link_harnesses();
+    let functions = scan.functions;
+
+    if let Some(challenge) = &args.challenge {
+        let markdown = fs::read_to_string(challenge)
+            .map_err(|err| format!("cannot read `{}`: {err}", challenge.display()))?;
+        let entries = report::check_challenge(&markdown, &functions);
+        let json = serde_json::to_string_pretty(&entries).expect("entries are always serializable");
+        output(&args, &json, &report::challenge_markdown(&entries))?;
+        let done = entries
+            .iter()
+            .all(|entry| entry.status == report::Status::Done);
+        return Ok(if done {
+            ExitCode::SUCCESS
+        } else {
+            ExitCode::FAILURE
+        });
+    }
+
+    output(
+        &args,
+        &report::json(&functions),
+        &report::markdown(&functions),
+    )?;
+    Ok(ExitCode::SUCCESS)
+}
+
+fn output(args: &Args, json: &str, markdown: &str) -> Result<(), String> {
+    let write = |path: &Path, contents: &str| {
+        fs::write(path, contents).map_err(|err| format!("cannot write `{}`: {err}", path.display()))
+    };
+    if let Some(path) = &args.json {
+        write(path, json)?;
+    }
+    if let Some(path) = &args.markdown {
+        write(path, markdown)?;
+    }
+    if args.json.is_none() && args.markdown.is_none() {
+        print!("{markdown}");
+    }
+    Ok(())
+}
+
+/// Scan every `.rs` file under `dir`, in a stable order.
+fn scan_dir(krate: &str, src: &Path, dir: &Path, scan: &mut Scan) -> Result<(), String> {
+    let mut entries: Vec<PathBuf> = fs::read_dir(dir)
+        .and_then(|entries| {
+            entries
+                .map(|entry| entry.map(|entry| entry.path()))
+                .collect()
+        })
+        .map_err(|err| format!("cannot read `{}`: {err}", dir.display()))?;
+    entries.sort();
+    for path in entries {
+        if path.is_dir() {
+            scan_dir(krate, src, &path, scan)?;
+        } else if path.extension().is_some_and(|ext| ext == "rs") {
+            let source = fs::read_to_string(&path)
+                .map_err(|err| format!("cannot read `{}`: {err}", path.display()))?;
+            let module = module_path(src, &path);
+            match scan::scan_file(krate, &module, &path.display().to_string(), &source) {
+                Ok(file_scan) => scan.extend(file_scan),
+                // Some files rely on unstable syntax that `syn` does not support yet.
+                Err(err) => eprintln!("warning: skipping `{}`: {err}", path.display()),
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The module path of a file, e.g., `ptr/non_null.rs` is `ptr::non_null`.
+///
+/// This ignores `#[path]` attributes, which are rare in the library.
+fn module_path(src: &Path, file: &Path) -> Vec<String> {
+    let relative = file.strip_prefix(src).unwrap_or(file).with_extension("");
+    let mut module: Vec<String> = relative
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy().into_owned())
+        .collect();
+    if matches!(module.last().map(String::as_str), Some("mod" | "lib")) {
+        module.pop();
+    }
+    module
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn module_paths() {
+        let src = Path::new("library/core/src");
+        assert!(module_path(src, &src.join("lib.rs")).is_empty());
+        assert_eq!(module_path(src, &src.join("ptr/mod.rs")), ["ptr"]);
+        assert_eq!(
+            module_path(src, &src.join("ptr/non_null.rs")),
+            ["ptr", "non_null"]
+        );
+    }
+}
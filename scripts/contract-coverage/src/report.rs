@@ -0,0 +1,220 @@
+//! Render the scan results, and check them against the function tables of a challenge.
+
+use std::fmt::Write;
+
+use serde::Serialize;
+
+use crate::scan::Function;
+
+/// The functions that use `unsafe`, as JSON.
+pub fn json(functions: &[Function]) -> String {
+    let functions: Vec<&Function> = functions.iter().filter(|func| func.uses_unsafe()).collect();
+    serde_json::to_string_pretty(&functions).expect("functions are always serializable")
+}
+
+/// A markdown table of the functions that use `unsafe`, with the same first columns as the tables
+/// in `doc/src/challenges`.
+pub fn markdown(functions: &[Function]) -> String {
+    let mut out = String::from(
+        "| Function | Location | Unsafe | Contracts | Harnesses |\n\
+         |----------|----------|--------|-----------|-----------|\n",
+    );
+    for func in functions.iter().filter(|func| func.uses_unsafe()) {
+        writeln!(
+            out,
+            "| {} | {} | {} | {} | {} |",
+            func.name,
+            func.location(),
+            if func.is_unsafe { "fn" } else { "block" },
+            contracts(func),
+            func.harnesses.len(),
+        )
+        .unwrap();
+    }
+    out
+}
+
+fn contracts(func: &Function) -> String {
+    match (func.requires, func.ensures) {
+        (0, 0) => "-".to_string(),
+        (requires, 0) => format!("requires ×{requires}"),
+        (0, ensures) => format!("ensures ×{ensures}"),
+        (requires, ensures) => format!("requires ×{requires}, ensures ×{ensures}"),
+    }
+}
+
+/// A function listed in a challenge table.
+#[derive(Debug, PartialEq, Serialize)]
+pub struct Entry {
+    pub name: String,
+    pub location: String,
+    pub status: Status,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub enum Status {
+    /// No function with this name was found.
+    Missing,
+    NoContract,
+    NoHarness,
+    Done,
+}
+
+/// Parse the `| Function | Location |` tables of a challenge description.
+pub fn challenge_entries(markdown: &str) -> Vec<(String, String)> {
+    let mut entries = Vec::new();
+    let mut in_table = false;
+    for line in markdown.lines().map(str::trim) {
+        if !line.starts_with('|') {
+            in_table = false;
+            continue;
+        }
+        let cells: Vec<&str> = line
+            .trim_matches('|')
+            .split('|')
+            .map(|cell| cell.trim().trim_matches('`'))
+            .collect();
+        if cells.first() == Some(&"Function") {
+            in_table = true;
+        } else if in_table && cells.len() >= 2 && !cells[0].starts_with('-') {
+            entries.push((cells[0].to_string(), cells[1].to_string()));
+        }
+    }
+    entries
+}
+
+/// Find the status of every function of a challenge.
+///
+/// Functions are matched by name within the crate of the given location; when several functions
+/// share the name, the ones in the given location are preferred.
+pub fn check_challenge(markdown: &str, functions: &[Function]) -> Vec<Entry> {
+    challenge_entries(markdown)
+        .into_iter()
+        .map(|(name, location)| {
+            let suffix = format!("::{name}");
+            let candidates: Vec<&Function> = functions
+                .iter()
+                .filter(|func| func.name == name || func.name.ends_with(&suffix))
+                .collect();
+            let in_location: Vec<&Function> = candidates
+                .iter()
+                .copied()
+                .filter(|func| func.location() == location)
+                .collect();
+            // Locations in the challenges are not always exact, so fall back to the whole crate,
+            // including the crates that `std` re-exports.
+            let krate = location.split("::").next().unwrap_or_default();
+            let crates: &[&str] = if krate == "std" {
+                &["std", "core", "alloc"]
+            } else {
+                &[krate]
+            };
+            let matches = if in_location.is_empty() {
+                candidates
+                    .into_iter()
+                    .filter(|func| crates.contains(&func.krate.as_str()))
+                    .collect()
+            } else {
+                in_location
+            };
+            let status = if matches.is_empty() {
+                Status::Missing
+            } else if !matches.iter().all(|func| func.has_contract()) {
+                Status::NoContract
+            } else if matches.iter().any(|func| func.harnesses.is_empty()) {
+                Status::NoHarness
+            } else {
+                Status::Done
+            };
+            Entry {
+                name,
+                location,
+                status,
+            }
+        })
+        .collect()
+}
+
+pub fn challenge_markdown(entries: &[Entry]) -> String {
+    let mut out = String::from(
+        "| Function | Location | Status |\n\
+         |----------|----------|--------|\n",
+    );
+    for entry in entries {
+        let status = match entry.status {
+            Status::Missing => "not found",
+            Status::NoContract => "missing contract",
+            Status::NoHarness => "missing harness",
+            Status::Done => "done",
+        };
+        writeln!(out, "| {} | {} | {} |", entry.name, entry.location, status).unwrap();
+    }
+    let done = entries
+        .iter()
+        .filter(|entry| entry.status == Status::Done)
+        .count();
+    writeln!(
+        out,
+        "\n{done}/{} functions have contracts and harnesses.",
+        entries.len()
+    )
+    .unwrap();
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CHALLENGE: &str = "
+### Part 1
+
+| Function | Location |
+|---------|---------|
+|typed_swap | core::intrinsics |
+| `*const T::add` | core::ptr |
+|missing| core::intrinsics |
+
+Other text | with a pipe.
+";
+
+    fn function(name: &str, module: &str, requires: usize, harnesses: &[&str]) -> Function {
+        Function {
+            krate: "core".to_string(),
+            module: module.to_string(),
+            name: name.to_string(),
+            file: String::new(),
+            line: 1,
+            is_unsafe: true,
+            has_unsafe_block: false,
+            requires,
+            ensures: 0,
+            harnesses: harnesses.iter().map(|h| h.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn parses_challenge_tables() {
+        assert_eq!(
+            challenge_entries(CHALLENGE),
+            [
+                ("typed_swap".to_string(), "core::intrinsics".to_string()),
+                ("*const T::add".to_string(), "core::ptr".to_string()),
+                ("missing".to_string(), "core::intrinsics".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn checks_challenge_status() {
+        let functions = [
+            function("typed_swap", "intrinsics", 1, &["mem::verify::check_swap"]),
+            function("*const T::add", "ptr::const_ptr", 1, &[]),
+        ];
+        let status: Vec<Status> = check_challenge(CHALLENGE, &functions)
+            .into_iter()
+            .map(|entry| entry.status)
+            .collect();
+        assert_eq!(status, [Status::Done, Status::NoHarness, Status::Missing]);
+    }
+}
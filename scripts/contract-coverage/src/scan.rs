@@ -0,0 +1,738 @@
+//! Collect the functions that use `unsafe` and the contract harnesses from the library sources.
+
+use std::cmp::Reverse;
+
+use proc_macro2::{TokenStream, TokenTree};
+use quote::ToTokens;
+use serde::Serialize;
+use syn::punctuated::Punctuated;
+use syn::visit::{self, Visit};
+use syn::{Attribute, Block, Meta, Signature, Token};
+
+/// A function of the library, outside of the code that is only compiled for verification.
+///
+/// Every function is a candidate target for harnesses, but only the functions that use `unsafe`
+/// are reported.
+#[derive(Clone, Debug, Serialize)]
+pub struct Function {
+    /// The crate the function belongs to, e.g., `core`.
+    pub krate: String,
+    /// The module path inside the crate, e.g., `ptr::non_null`.
+    pub module: String,
+    /// The function name, qualified by its `impl` or `trait` type if any, e.g., `NonNull::add`.
+    pub name: String,
+    pub file: String,
+    pub line: usize,
+    pub is_unsafe: bool,
+    pub has_unsafe_block: bool,
+    pub requires: usize,
+    pub ensures: usize,
+    /// The harnesses that verify the contract of this function.
+    pub harnesses: Vec<String>,
+}
+
+impl Function {
+    /// The location as written in the challenge tables, e.g., `core::ptr`.
+    pub fn location(&self) -> String {
+        if self.module.is_empty() {
+            self.krate.clone()
+        } else {
+            format!("{}::{}", self.krate, self.module)
+        }
+    }
+
+    pub fn has_contract(&self) -> bool {
+        self.requires + self.ensures > 0
+    }
+
+    /// Whether the function is `unsafe` or contains `unsafe` blocks.
+    pub fn uses_unsafe(&self) -> bool {
+        self.is_unsafe || self.has_unsafe_block
+    }
+
+    /// Path segments of the function, starting from the crate root.
+    fn segments(&self) -> Vec<&str> {
+        self.module
+            .split("::")
+            .filter(|s| !s.is_empty())
+            .chain(self.name.split("::"))
+            .collect()
+    }
+}
+
+/// A `proof_for_contract` harness.
+#[derive(Clone, Debug, Serialize)]
+pub struct Harness {
+    pub krate: String,
+    pub module: String,
+    pub name: String,
+    /// The function whose contract is verified, without generic arguments.
+    pub target: String,
+    pub file: String,
+    pub line: usize,
+}
+
+#[derive(Debug, Default)]
+pub struct Scan {
+    pub functions: Vec<Function>,
+    pub harnesses: Vec<Harness>,
+    /// Out-of-line modules that are only compiled for verification, e.g., `ptr::arbitrary`.
+    pub verification_modules: Vec<(String, String)>,
+}
+
+impl Scan {
+    pub fn extend(&mut self, other: Scan) {
+        self.functions.extend(other.functions);
+        self.harnesses.extend(other.harnesses);
+        self.verification_modules.extend(other.verification_modules);
+    }
+
+    /// Drop the functions of the out-of-line modules that are only compiled for verification,
+    /// which are harnesses and their helpers. This needs every file of the crate to be scanned.
+    pub fn drop_verification_functions(&mut self) {
+        let modules = &self.verification_modules;
+        self.functions.retain(|func| {
+            !modules.iter().any(|(krate, module)| {
+                func.krate == *krate
+                    && (func.module == *module || func.module.starts_with(&format!("{module}::")))
+            })
+        });
+    }
+
+    /// Attach each harness to the function it verifies.
+    ///
+    /// Harness targets are paths relative to the harness module. Paths that start with `crate`,
+    /// `super` or `self` must name the function exactly. Other paths usually name an item that
+    /// the harness module imports from an ancestor, so we pick the function whose path ends with
+    /// the target and that shares the longest module prefix with the harness. On a tie, the
+    /// function closest to that prefix wins. Either way, the target must include the `impl` type
+    /// of a method, and harnesses whose target names no function are not linked.
+    pub fn link_harnesses(&mut self) {
+        let paths: Vec<(Vec<&str>, usize)> = self
+            .functions
+            .iter()
+            .map(|func| (func.segments(), func.name.split("::").count()))
+            .collect();
+        let mut links = Vec::new();
+        for harness in &self.harnesses {
+            let harness_module: Vec<&str> = harness
+                .module
+                .split("::")
+                .filter(|s| !s.is_empty())
+                .collect();
+            let absolute = resolve(&harness.target, &harness_module);
+            let target: Vec<&str> = harness.target.split("::").collect();
+            let best = self
+                .functions
+                .iter()
+                .zip(&paths)
+                .enumerate()
+                .filter(|(_, (func, (path, name_len)))| {
+                    func.krate == harness.krate
+                        && match &absolute {
+                            Some(absolute) => path == absolute,
+                            None => path.ends_with(&target) && target.len() >= *name_len,
+                        }
+                })
+                .max_by_key(|(_, (func, _))| {
+                    let module: Vec<&str> =
+                        func.module.split("::").filter(|s| !s.is_empty()).collect();
+                    let common = module
+                        .iter()
+                        .zip(&harness_module)
+                        .take_while(|(a, b)| a == b)
+                        .count();
+                    (common, Reverse(module.len()))
+                })
+                .map(|(idx, _)| idx);
+            if let Some(idx) = best {
+                links.push((idx, format!("{}::{}", harness.module, harness.name)));
+            }
+        }
+        for (idx, harness) in links {
+            self.functions[idx].harnesses.push(harness);
+        }
+    }
+}
+
+/// The path of a harness target from the crate root, if it starts with `crate`, `super` or
+/// `self`, e.g., `super::u8` in `num::int_log10::verify` is `num::int_log10::u8`.
+fn resolve<'a>(target: &'a str, harness_module: &[&'a str]) -> Option<Vec<&'a str>> {
+    let mut segments = target.split("::").peekable();
+    let mut path = match segments.peek() {
+        Some(&"crate") => Vec::new(),
+        Some(&("super" | "self")) => harness_module.to_vec(),
+        _ => return None,
+    };
+    for segment in segments {
+        match segment {
+            "crate" | "self" => {}
+            "super" => {
+                path.pop()?;
+            }
+            segment => path.push(segment),
+        }
+    }
+    Some(path)
+}
+
+/// Scan the contents of one source file.
+pub fn scan_file(krate: &str, module: &[String], file: &str, source: &str) -> syn::Result<Scan> {
+    let ast = syn::parse_file(source)?;
+    let mut visitor = Visitor {
+        krate,
+        file,
+        module: module.to_vec(),
+        owner: Vec::new(),
+        verification_depth: 0,
+        scan: Scan::default(),
+    };
+    visitor.visit_file(&ast);
+    Ok(visitor.scan)
+}
+
+struct Visitor<'a> {
+    krate: &'a str,
+    file: &'a str,
+    module: Vec<String>,
+    /// Stack of the `impl` and `trait` types we are in.
+    owner: Vec<String>,
+    /// Number of inline modules we are in that are only compiled for verification.
+    verification_depth: usize,
+    scan: Scan,
+}
+
+impl Visitor<'_> {
+    fn record(&mut self, attrs: &[Attribute], sig: &Signature, block: Option<&Block>) {
+        let name = match self.owner.last() {
+            Some(owner) => format!("{owner}::{}", sig.ident),
+            None => sig.ident.to_string(),
+        };
+        let line = sig.ident.span().start().line;
+        let module = self.module.join("::");
+
+        for attr in attrs {
+            if let Some(target) = harness_target(attr) {
+                self.record_harness(&name, target, line);
+            }
+        }
+        // Harnesses and their helpers are not part of the library.
+        if self.verification_depth > 0 || attrs.iter().any(is_harness_attr) {
+            return;
+        }
+
+        let is_unsafe = sig.unsafety.is_some();
+        let has_unsafe_block = block.is_some_and(contains_unsafe_block);
+        let (requires, ensures) = attrs.iter().fold((0, 0), |(requires, ensures), attr| {
+            let names = contract_names(attr);
+            (
+                requires + names.iter().filter(|n| *n == "requires").count(),
+                ensures + names.iter().filter(|n| *n == "ensures").count(),
+            )
+        });
+        self.scan.functions.push(Function {
+            krate: self.krate.to_string(),
+            module,
+            name,
+            file: self.file.to_string(),
+            line,
+            is_unsafe,
+            has_unsafe_block,
+            requires,
+            ensures,
+            harnesses: Vec::new(),
+        });
+    }
+
+    fn record_harness(&mut self, name: &str, target: String, line: usize) {
+        self.scan.harnesses.push(Harness {
+            krate: self.krate.to_string(),
+            module: self.module.join("::"),
+            name: name.to_string(),
+            target,
+            file: self.file.to_string(),
+            line,
+        });
+    }
+
+    /// Record the harnesses defined in the body of a `macro_rules!`, which are usually
+    /// instantiated for several types.
+    ///
+    /// Metavariables are replaced by their name, so a harness for `<*const $ty>::add` verifies
+    /// `*const T::add`. A metavariable in place of the mutability of a pointer stands for both,
+    /// so a harness for `<*$m $ty>::add` verifies `*const T::add` and `*mut T::add`. Harnesses
+    /// are attributed to the module of the macro.
+    fn record_macro_harnesses(&mut self, tokens: &TokenStream) {
+        let tokens: Vec<TokenTree> = tokens.clone().into_iter().collect();
+        let mut targets = Vec::new();
+        for (idx, token) in tokens.iter().enumerate() {
+            match (token, tokens.get(idx + 1)) {
+                (TokenTree::Punct(punct), Some(TokenTree::Group(group)))
+                    if punct.as_char() == '#'
+                        && group.delimiter() == proc_macro2::Delimiter::Bracket =>
+                {
+                    for stream in expand_mutability(&group.stream()) {
+                        let meta = syn::parse2::<Meta>(strip_metavariables(&stream));
+                        if let Ok(Meta::List(list)) = meta {
+                            if last_segment(&list.path).as_deref() == Some("proof_for_contract") {
+                                targets.push(strip_generics(&list.tokens));
+                            }
+                        }
+                    }
+                }
+                (TokenTree::Ident(keyword), Some(TokenTree::Ident(name))) if keyword == "fn" => {
+                    let line = name.span().start().line;
+                    for target in std::mem::take(&mut targets) {
+                        self.record_harness(&name.to_string(), target, line);
+                    }
+                }
+                (TokenTree::Group(group), _) => self.record_macro_harnesses(&group.stream()),
+                _ => {}
+            }
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for Visitor<'_> {
+    fn visit_item_mod(&mut self, item: &'ast syn::ItemMod) {
+        let verification = item.attrs.iter().any(is_cfg_kani);
+        self.module.push(item.ident.to_string());
+        if item.content.is_some() {
+            self.verification_depth += usize::from(verification);
+            visit::visit_item_mod(self, item);
+            self.verification_depth -= usize::from(verification);
+        } else if verification {
+            let module = (self.krate.to_string(), self.module.join("::"));
+            self.scan.verification_modules.push(module);
+        }
+        self.module.pop();
+    }
+
+    fn visit_item_impl(&mut self, item: &'ast syn::ItemImpl) {
+        self.owner.push(type_name(&item.self_ty));
+        visit::visit_item_impl(self, item);
+        self.owner.pop();
+    }
+
+    fn visit_item_trait(&mut self, item: &'ast syn::ItemTrait) {
+        self.owner.push(item.ident.to_string());
+        visit::visit_item_trait(self, item);
+        self.owner.pop();
+    }
+
+    fn visit_item_macro(&mut self, item: &'ast syn::ItemMacro) {
+        if item.mac.path.is_ident("macro_rules") {
+            self.record_macro_harnesses(&item.mac.tokens);
+        }
+    }
+
+    fn visit_item_fn(&mut self, item: &'ast syn::ItemFn) {
+        // Functions nested in other functions are not reachable by path, so we stop here.
+        self.record(&item.attrs, &item.sig, Some(&item.block));
+    }
+
+    fn visit_impl_item_fn(&mut self, item: &'ast syn::ImplItemFn) {
+        self.record(&item.attrs, &item.sig, Some(&item.block));
+    }
+
+    fn visit_trait_item_fn(&mut self, item: &'ast syn::TraitItemFn) {
+        self.record(&item.attrs, &item.sig, item.default.as_ref());
+    }
+
+    fn visit_foreign_item_fn(&mut self, item: &'ast syn::ForeignItemFn) {
+        // Items in `extern` blocks, such as intrinsics, are always unsafe to call.
+        let mut sig = item.sig.clone();
+        sig.unsafety = Some(Default::default());
+        self.record(&item.attrs, &sig, None);
+    }
+}
+
+/// The last path segment of a type, e.g., `NonNull` for `NonNull<T>`.
+fn type_name(ty: &syn::Type) -> String {
+    match ty {
+        syn::Type::Path(path) => match path.path.segments.last() {
+            Some(segment) => segment.ident.to_string(),
+            None => String::new(),
+        },
+        // Methods of pointers and slices are generic over the pointee, so harnesses for
+        // `<*const u8>::add` verify `*const T::add`.
+        syn::Type::Ptr(ptr) => {
+            let mutability = if ptr.mutability.is_some() {
+                "mut"
+            } else {
+                "const"
+            };
+            let pointee = if matches!(*ptr.elem, syn::Type::Slice(_)) {
+                "[T]"
+            } else {
+                "T"
+            };
+            format!("*{mutability} {pointee}")
+        }
+        syn::Type::Reference(reference) => type_name(&reference.elem),
+        syn::Type::Slice(_) => "[T]".to_string(),
+        other => other.to_token_stream().to_string(),
+    }
+}
+
+/// Names of the contract attributes in `attr`, looking through `cfg_attr`.
+fn contract_names(attr: &Attribute) -> Vec<String> {
+    let path = attr.path();
+    if path.is_ident("cfg_attr") {
+        let Ok(nested) = attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+        else {
+            return Vec::new();
+        };
+        return nested
+            .iter()
+            .skip(1)
+            .filter_map(|meta| last_segment(meta.path()))
+            .collect();
+    }
+    last_segment(path).into_iter().collect()
+}
+
+fn last_segment(path: &syn::Path) -> Option<String> {
+    path.segments
+        .last()
+        .map(|segment| segment.ident.to_string())
+}
+
+/// Whether `attr` is `#[cfg(kani)]`.
+fn is_cfg_kani(attr: &Attribute) -> bool {
+    match &attr.meta {
+        Meta::List(list) => list.path.is_ident("cfg") && list.tokens.to_string() == "kani",
+        _ => false,
+    }
+}
+
+/// Whether `attr` marks a harness or a test, e.g., `#[safety::harness]` or `#[kani::proof]`.
+fn is_harness_attr(attr: &Attribute) -> bool {
+    matches!(
+        last_segment(attr.path()).as_deref(),
+        Some("harness" | "proof" | "proof_for_contract" | "test")
+    )
+}
+
+/// The target of a `proof_for_contract` attribute, if `attr` is one.
+fn harness_target(attr: &Attribute) -> Option<String> {
+    if last_segment(attr.path()).as_deref() != Some("proof_for_contract") {
+        return None;
+    }
+    let Meta::List(list) = &attr.meta else {
+        return None;
+    };
+    Some(strip_generics(&list.tokens))
+}
+
+/// Render a path without whitespace nor generic arguments, e.g., `Unique::cast<U>` becomes
+/// `Unique::cast`, `<*const u8>::add` becomes `*const T::add`, and
+/// `<NonNull<u8> as From<&u8>>::from` becomes `NonNull::from`.
+fn strip_generics(tokens: &TokenStream) -> String {
+    let Ok(path) = syn::parse2::<syn::TypePath>(tokens.clone()) else {
+        return tokens.to_string().replace(' ', "");
+    };
+    let mut segments = Vec::new();
+    let mut skip = 0;
+    if let Some(qself) = &path.qself {
+        // The trait of `<T as Trait>::f` is not part of the name of the function.
+        segments.push(type_name(&qself.ty));
+        skip = qself.position;
+    }
+    segments.extend(
+        path.path
+            .segments
+            .iter()
+            .skip(skip)
+            .map(|segment| segment.ident.to_string()),
+    );
+    segments.join("::")
+}
+
+/// Drop the `$` of macro metavariables, e.g., `<*const $ty>::add` becomes `<*const ty>::add`.
+fn strip_metavariables(tokens: &TokenStream) -> TokenStream {
+    tokens
+        .clone()
+        .into_iter()
+        .filter_map(|token| match token {
+            TokenTree::Punct(punct) if punct.as_char() == '$' => None,
+            TokenTree::Group(group) => {
+                let stream = strip_metavariables(&group.stream());
+                Some(proc_macro2::Group::new(group.delimiter(), stream).into())
+            }
+            token => Some(token),
+        })
+        .collect()
+}
+
+/// Expand a metavariable in place of the mutability of a pointer, e.g., `<*$m $ty>::add` becomes
+/// `<*const $ty>::add` and `<*mut $ty>::add`.
+fn expand_mutability(tokens: &TokenStream) -> Vec<TokenStream> {
+    let constant = replace_mutability(tokens, "const");
+    if constant.to_string() == tokens.to_string() {
+        return vec![constant];
+    }
+    vec![constant, replace_mutability(tokens, "mut")]
+}
+
+fn replace_mutability(tokens: &TokenStream, mutability: &str) -> TokenStream {
+    let tokens: Vec<TokenTree> = tokens.clone().into_iter().collect();
+    let mut replaced = Vec::new();
+    let mut idx = 0;
+    while idx < tokens.len() {
+        match (&tokens[idx], tokens.get(idx + 1), tokens.get(idx + 2)) {
+            (
+                TokenTree::Punct(star),
+                Some(TokenTree::Punct(dollar)),
+                Some(TokenTree::Ident(name)),
+            ) if star.as_char() == '*' && dollar.as_char() == '$' => {
+                replaced.push(tokens[idx].clone());
+                replaced.push(proc_macro2::Ident::new(mutability, name.span()).into());
+                idx += 3;
+                continue;
+            }
+            (TokenTree::Group(group), _, _) => {
+                let stream = replace_mutability(&group.stream(), mutability);
+                replaced.push(proc_macro2::Group::new(group.delimiter(), stream).into());
+            }
+            (token, _, _) => replaced.push(token.clone()),
+        }
+        idx += 1;
+    }
+    replaced.into_iter().collect()
+}
+
+fn contains_unsafe_block(block: &Block) -> bool {
+    let mut finder = UnsafeFinder { found: false };
+    finder.visit_block(block);
+    finder.found
+}
+
+struct UnsafeFinder {
+    found: bool,
+}
+
+impl<'ast> Visit<'ast> for UnsafeFinder {
+    fn visit_expr_unsafe(&mut self, _: &'ast syn::ExprUnsafe) {
+        self.found = true;
+    }
+
+    fn visit_item(&mut self, _: &'ast syn::Item) {
+        // Nested items are not part of the function body.
+    }
+
+    fn visit_macro(&mut self, mac: &'ast syn::Macro) {
+        // Macro arguments are not parsed, so look for `unsafe { .. }` in the raw tokens.
+        self.found |= tokens_contain_unsafe_block(&mac.tokens);
+    }
+}
+
+fn tokens_contain_unsafe_block(tokens: &TokenStream) -> bool {
+    let mut prev_unsafe = false;
+    for token in tokens.clone() {
+        match &token {
+            TokenTree::Group(group) => {
+                if prev_unsafe && group.delimiter() == proc_macro2::Delimiter::Brace {
+                    return true;
+                }
+                if tokens_contain_unsafe_block(&group.stream()) {
+                    return true;
+                }
+                prev_unsafe = false;
+            }
+            TokenTree::Ident(ident) => prev_unsafe = ident == "unsafe",
+            _ => prev_unsafe = false,
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = r#"
+        pub struct Unique<T>(*mut T);
+
+        impl<T> Unique<T> {
+            #[requires(!ptr.is_null())]
+            #[ensures(|result| result.as_ptr() == ptr)]
+            pub const unsafe fn new_unchecked(ptr: *mut T) -> Self { Unique(ptr) }
+
+            pub fn as_ref(&self) -> &T { unsafe { &*self.0 } }
+
+            pub fn safe(&self) -> usize { 0 }
+
+            pub unsafe fn from_raw_parts(ptr: *mut T) -> Self { Unique(ptr) }
+        }
+
+        pub fn from_raw_parts<T>(ptr: *mut T) -> *mut T { ptr }
+
+        #[cfg_attr(kani, kani::requires(x.is_some()))]
+        pub unsafe fn free(x: Option<u8>) -> u8 { x.unwrap_unchecked() }
+
+        pub fn in_macro() { debug_assert!(unsafe { free(Some(1)) } == 1) }
+
+        extern "rust-intrinsic" {
+            pub fn intrinsic(x: u8);
+        }
+
+        #[cfg(kani)]
+        mod arbitrary;
+
+        #[cfg(kani)]
+        mod verify {
+            use super::*;
+
+            fn any_ptr() -> *mut u8 { unsafe { free(None) as *mut u8 } }
+
+            #[safety::proof_for_contract(from_raw_parts::<u8>)]
+            fn check_from_raw_parts() {}
+
+            #[safety::proof_for_contract(super::free)]
+            fn check_free_exactly() {}
+
+            #[safety::proof_for_contract(super::super::free)]
+            fn check_free_outside() {}
+
+            #[safety::proof_for_contract(Unique::<T>::new_unchecked)]
+            fn check_new_unchecked() {}
+
+            #[kani::proof_for_contract(free)]
+            fn check_free() {}
+
+            #[safety::proof_for_contract(<*const u8>::add)]
+            fn check_add() {}
+
+            #[safety::proof_for_contract(<Unique<u8> as From<&mut u8>>::from)]
+            fn check_from() {}
+
+            macro_rules! generate_harnesses {
+                ($module:ident, $ty:ty) => {
+                    mod $module {
+                        #[safety::proof_for_contract(<*const $ty>::sub)]
+                        fn check_sub() {}
+
+                        #[safety::proof_for_contract(<*$m $ty>::offset)]
+                        fn check_offset() {}
+                    }
+                };
+            }
+        }
+    "#;
+
+    fn scan() -> Scan {
+        let mut scan = scan_file("core", &["ptr".to_string()], "ptr.rs", SOURCE).unwrap();
+        scan.drop_verification_functions();
+        scan.link_harnesses();
+        scan
+    }
+
+    fn find<'a>(scan: &'a Scan, name: &str) -> &'a Function {
+        scan.functions
+            .iter()
+            .find(|func| func.name == name)
+            .unwrap()
+    }
+
+    #[test]
+    fn collects_functions() {
+        let scan = scan();
+        let names: Vec<&str> = scan
+            .functions
+            .iter()
+            .map(|func| func.name.as_str())
+            .collect();
+        // Harnesses and the functions of `#[cfg(kani)]` modules are left out.
+        assert_eq!(
+            names,
+            [
+                "Unique::new_unchecked",
+                "Unique::as_ref",
+                "Unique::safe",
+                "Unique::from_raw_parts",
+                "from_raw_parts",
+                "free",
+                "in_macro",
+                "intrinsic"
+            ]
+        );
+        assert_eq!(
+            scan.verification_modules,
+            [("core".to_string(), "ptr::arbitrary".to_string())]
+        );
+
+        let as_ref = find(&scan, "Unique::as_ref");
+        assert!(!as_ref.is_unsafe && as_ref.has_unsafe_block);
+        assert!(find(&scan, "intrinsic").is_unsafe);
+        assert!(find(&scan, "in_macro").has_unsafe_block);
+        assert!(!find(&scan, "Unique::safe").uses_unsafe());
+    }
+
+    #[test]
+    fn counts_contracts() {
+        let scan = scan();
+        let new_unchecked = find(&scan, "Unique::new_unchecked");
+        assert_eq!((new_unchecked.requires, new_unchecked.ensures), (1, 1));
+        assert_eq!(find(&scan, "free").requires, 1);
+        assert!(!find(&scan, "Unique::as_ref").has_contract());
+    }
+
+    #[test]
+    fn links_harnesses() {
+        let scan = scan();
+        assert_eq!(scan.harnesses[3].target, "Unique::new_unchecked");
+        assert_eq!(scan.harnesses[5].target, "*const T::add");
+        assert_eq!(scan.harnesses[6].target, "Unique::from");
+        assert_eq!(
+            find(&scan, "Unique::new_unchecked").harnesses,
+            ["ptr::verify::check_new_unchecked"]
+        );
+        assert_eq!(
+            find(&scan, "free").harnesses,
+            ["ptr::verify::check_free_exactly", "ptr::verify::check_free"]
+        );
+        assert!(find(&scan, "Unique::as_ref").harnesses.is_empty());
+    }
+
+    #[test]
+    fn links_harnesses_only_to_the_named_function() {
+        let scan = scan();
+        // A bare name does not name a method, even if no free function uses `unsafe`.
+        assert_eq!(
+            find(&scan, "from_raw_parts").harnesses,
+            ["ptr::verify::check_from_raw_parts"]
+        );
+        assert!(find(&scan, "Unique::from_raw_parts").harnesses.is_empty());
+        // `super::super::free` names a function at the root of the crate, where there is none.
+        let linked = scan
+            .functions
+            .iter()
+            .flat_map(|func| &func.harnesses)
+            .any(|harness| harness == "ptr::verify::check_free_outside");
+        assert!(!linked);
+    }
+
+    #[test]
+    fn finds_harnesses_in_macros() {
+        let scan = scan();
+        let harnesses: Vec<(&str, &str, &str)> = scan.harnesses[7..]
+            .iter()
+            .map(|harness| {
+                (
+                    harness.module.as_str(),
+                    harness.name.as_str(),
+                    harness.target.as_str(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            harnesses,
+            [
+                ("ptr::verify", "check_sub", "*const T::sub"),
+                ("ptr::verify", "check_offset", "*const T::offset"),
+                ("ptr::verify", "check_offset", "*mut T::offset"),
+            ]
+        );
+    }
+}